@@ -4,34 +4,63 @@
 // This one is hard to avoid.
 #![allow(clippy::multiple_crate_versions)]
 
+use std::ffi::OsStr;
 use std::fs::read_dir;
 use std::io::{Error, ErrorKind, Result};
 use std::path::Path;
 
-fn generate_proto_sources() -> Result<()> {
-    let path = String::from("parsec-operations/protobuf");
-    let dir_entries = read_dir(Path::new(&path))?;
+// Contracts of the parsec-operations submodule.
+const OPERATIONS_PROTOS_PATH: &str = "parsec-operations/protobuf";
+// Contracts not yet available in the parsec-operations submodule. They are moved there once it
+// defines them, so a contract must not be in both places.
+const LOCAL_PROTOS_PATH: &str = "protobuf";
+
+fn proto_files(path: &str) -> Result<Vec<String>> {
+    let dir_entries = read_dir(Path::new(path))?;
     let files: Result<Vec<String>> = dir_entries
         .map(|protos_file| {
             protos_file?
                 .path()
                 .into_os_string()
                 .into_string()
-                .or_else(|_| {
-                    Err(Error::new(
+                .map_err(|_| {
+                    Error::new(
                         ErrorKind::InvalidData,
                         "conversion from OsString to String failed",
-                    ))
+                    )
                 })
         })
         // Fail the entire operation if there was an error.
         .collect();
-    let proto_files: Vec<String> = files?
+    Ok(files?
         .into_iter()
         .filter(|string| string.ends_with(".proto"))
-        .collect();
-    let files_slices: Vec<&str> = proto_files.iter().map(|file| &file[..]).collect();
-    prost_build::compile_protos(&files_slices, &[&path])
+        .collect())
+}
+
+fn file_name(file: &str) -> Option<&OsStr> {
+    Path::new(file).file_name()
+}
+
+fn generate_proto_sources() -> Result<()> {
+    let mut files = proto_files(OPERATIONS_PROTOS_PATH)?;
+    for local_file in proto_files(LOCAL_PROTOS_PATH)? {
+        if files
+            .iter()
+            .any(|file| file_name(file) == file_name(&local_file))
+        {
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!(
+                    "{} is also defined in {}",
+                    local_file, OPERATIONS_PROTOS_PATH
+                ),
+            ));
+        }
+        files.push(local_file);
+    }
+    let files_slices: Vec<&str> = files.iter().map(|file| &file[..]).collect();
+    prost_build::compile_protos(&files_slices, &[LOCAL_PROTOS_PATH, OPERATIONS_PROTOS_PATH])
 }
 
 fn main() -> Result<()> {
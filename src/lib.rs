@@ -108,6 +108,8 @@
 //!# let mut stream = MockWrite { buffer: Vec::new() };
 //!use parsec_interface::operations::{Convert, NativeResult, psa_generate_key::Result};
 //!use parsec_interface::requests::{ProviderID, Opcode, BodyType, Response, ResponseStatus};
+//!use parsec_interface::requests::WireProtocolVersion;
+//!use parsec_interface::requests::common::HeaderFlags;
 //!use parsec_interface::requests::response::ResponseHeader;
 //!use parsec_interface::operations_protobuf::ProtobufConverter;
 //!
@@ -116,6 +118,8 @@
 //!let result_body = converter.result_to_body(result).unwrap();
 //!let response = Response {
 //!    header: ResponseHeader {
+//!        version: WireProtocolVersion::V1_0,
+//!        flags: HeaderFlags::empty(),
 //!        provider: ProviderID::MbedCrypto,
 //!        session: 0,
 //!        content_type: BodyType::Protobuf,
@@ -158,6 +162,8 @@
 //!# let mut stream = MockWrite { buffer: Vec::new() };
 //!use parsec_interface::operations::{Convert, NativeOperation};
 //!use parsec_interface::requests::{Request, ProviderID, BodyType, AuthType, Opcode};
+//!use parsec_interface::requests::WireProtocolVersion;
+//!use parsec_interface::requests::common::HeaderFlags;
 //!use parsec_interface::requests::request::{RequestHeader, RequestAuth};
 //!use parsec_interface::operations_protobuf::ProtobufConverter;
 //!use parsec_interface::operations::ping::Operation;
@@ -166,6 +172,8 @@
 //!let operation = NativeOperation::Ping(Operation {});
 //!let request = Request {
 //!    header: RequestHeader {
+//!        version: WireProtocolVersion::V1_0,
+//!        flags: HeaderFlags::empty(),
 //!        provider: ProviderID::Core,
 //!        session: 0,
 //!        content_type: BodyType::Protobuf,
@@ -0,0 +1,124 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # AttestKey operation
+//!
+//! Produce a token proving that a key is held by the provider, for example that it was generated
+//! in a hardware module and can not leave it.
+
+use super::key_attestation::{validate_attested_key, AttestationMechanism};
+use super::psa_key_attributes::Attributes;
+use crate::requests::ResponseStatus;
+
+/// Native object for the key attestation operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// Name of the key to attest.
+    pub attested_key_name: String,
+    /// Mechanism to use for the attestation.
+    pub mechanism: AttestationMechanism,
+    /// Nonce provided by the verifier, included in the token to prove its freshness.
+    pub nonce: Vec<u8>,
+    /// Name of the key to attest the key with. If not given, the provider uses its default
+    /// attesting key for the mechanism.
+    pub attesting_key_name: Option<String>,
+}
+
+/// Native object for the key attestation result.
+#[derive(Debug)]
+pub struct Result {
+    /// The attestation token, whose format depends on the mechanism.
+    pub token: Vec<u8>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the attested key
+    ///
+    /// This method checks that:
+    /// * the attested key is a key pair
+    /// * the nonce is not longer than the maximum length of the mechanism
+    pub fn validate(&self, attested_key_attributes: Attributes) -> crate::requests::Result<()> {
+        validate_attested_key(attested_key_attributes)?;
+        if let Some(max_nonce_length) = self.mechanism.max_nonce_length() {
+            if self.nonce.len() > max_nonce_length {
+                return Err(ResponseStatus::PsaErrorInvalidArgument);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash, SignHash};
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::RsaKeyPair,
+            bits: 2048,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: true,
+                    verify_message: false,
+                    sign_hash: true,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricSignature(
+                    AsymmetricSignature::RsaPkcs1v15Sign {
+                        hash_alg: SignHash::Specific(Hash::Sha256),
+                    },
+                ),
+            },
+        }
+    }
+
+    fn get_op(mechanism: AttestationMechanism, nonce_len: usize) -> Operation {
+        Operation {
+            attested_key_name: String::from("some key"),
+            mechanism,
+            nonce: vec![0xa5; nonce_len],
+            attesting_key_name: None,
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(AttestationMechanism::Tpm2Certify, 32)
+            .validate(get_attrs())
+            .unwrap();
+    }
+
+    #[test]
+    fn not_a_key_pair() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::RsaPublicKey;
+        assert_eq!(
+            get_op(AttestationMechanism::Tpm2Certify, 32)
+                .validate(attrs)
+                .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn nonce_too_long() {
+        assert_eq!(
+            get_op(AttestationMechanism::Tpm2Certify, 65)
+                .validate(get_attrs())
+                .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        get_op(AttestationMechanism::VendorSpecific(1), 65)
+            .validate(get_attrs())
+            .unwrap();
+    }
+}
@@ -0,0 +1,206 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # CanDoCrypto operation
+//!
+//! Checks if the provider supports a cryptographic algorithm, optionally used with a key of
+//! given attributes. Unlike `ListOpcodes`, which only tells if an operation is implemented,
+//! this operation tells which algorithms and key types a provider accepts.
+
+use super::psa_key_attributes::Attributes;
+use super::psa_raw_key_agreement::is_key_type_compatible;
+use crate::operations::psa_algorithm::{Algorithm, KeyAgreement};
+use crate::requests::ResponseStatus;
+
+/// Native object for the capability query operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// Attributes of the key that would be used with the algorithm. If not given, only the
+    /// support of the algorithm is checked.
+    pub attributes: Option<Attributes>,
+    /// The algorithm to check.
+    pub alg: Algorithm,
+}
+
+/// Native object for the capability query result.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Result {
+    /// `None` if the provider supports the algorithm with a key of the given attributes.
+    /// Otherwise, the response status that an operation using the algorithm and the key would
+    /// fail with. It is never `Success`.
+    pub reason: Option<ResponseStatus>,
+}
+
+impl Result {
+    /// Check if the provider supports the algorithm with a key of the given attributes.
+    pub fn supported(&self) -> bool {
+        self.reason.is_none()
+    }
+}
+
+impl Operation {
+    /// Validate the parts of the query that do not depend on the provider
+    ///
+    /// If attributes are given, this method checks that:
+    /// * the key policy allows the algorithm, or `PsaErrorNotPermitted` is returned
+    /// * the key type is compatible with the algorithm, or `PsaErrorInvalidArgument` is returned
+    ///
+    /// Providers can return the error status as the reason why the combination is not supported.
+    pub fn validate(&self) -> crate::requests::Result<()> {
+        if let Some(attributes) = self.attributes {
+            attributes.permits_alg(self.alg)?;
+            // psa-crypto does not accept key pairs for key agreement algorithms, so their key
+            // type is checked as PsaRawKeyAgreement does.
+            let compatible = match self.alg {
+                Algorithm::KeyAgreement(KeyAgreement::Raw(ka_alg))
+                | Algorithm::KeyAgreement(KeyAgreement::WithKeyDerivation { ka_alg, .. }) => {
+                    is_key_type_compatible(ka_alg, attributes.key_type)
+                }
+                alg => attributes.is_compatible_with_alg(alg),
+            };
+            if !compatible {
+                return Err(ResponseStatus::PsaErrorInvalidArgument);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{
+        AsymmetricSignature, Cipher, Hash, KeyDerivation, RawKeyAgreement, SignHash,
+    };
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: true,
+                    verify_message: false,
+                    sign_hash: true,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: SignHash::Specific(Hash::Sha256),
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        (Operation {
+            attributes: Some(get_attrs()),
+            alg: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                hash_alg: SignHash::Specific(Hash::Sha256),
+            }),
+        })
+        .validate()
+        .unwrap();
+    }
+
+    #[test]
+    fn validate_without_attributes() {
+        (Operation {
+            attributes: None,
+            alg: Algorithm::Cipher(Cipher::Ctr),
+        })
+        .validate()
+        .unwrap();
+    }
+
+    #[test]
+    fn algorithm_not_permitted() {
+        assert_eq!(
+            (Operation {
+                attributes: Some(get_attrs()),
+                alg: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: SignHash::Specific(Hash::Sha384),
+                }),
+            })
+            .validate()
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn incompatible_key_type() {
+        let mut attrs = get_attrs();
+        attrs.policy.permitted_algorithms = Algorithm::Cipher(Cipher::Ctr);
+        assert_eq!(
+            (Operation {
+                attributes: Some(attrs),
+                alg: Algorithm::Cipher(Cipher::Ctr),
+            })
+            .validate()
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn key_agreement() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.derive = true;
+        for alg in [
+            KeyAgreement::Raw(RawKeyAgreement::Ecdh),
+            KeyAgreement::WithKeyDerivation {
+                ka_alg: RawKeyAgreement::Ecdh,
+                kdf_alg: KeyDerivation::Hkdf {
+                    hash_alg: Hash::Sha256,
+                },
+            },
+        ]
+        .iter()
+        {
+            attrs.policy.permitted_algorithms = Algorithm::KeyAgreement(*alg);
+            (Operation {
+                attributes: Some(attrs),
+                alg: Algorithm::KeyAgreement(*alg),
+            })
+            .validate()
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn key_agreement_incompatible_key_type() {
+        let alg = Algorithm::KeyAgreement(KeyAgreement::Raw(RawKeyAgreement::Ffdh));
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.derive = true;
+        attrs.policy.permitted_algorithms = alg;
+        assert_eq!(
+            (Operation {
+                attributes: Some(attrs),
+                alg,
+            })
+            .validate()
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn supported() {
+        assert!(Result { reason: None }.supported());
+        assert!(!Result {
+            reason: Some(ResponseStatus::PsaErrorNotSupported),
+        }
+        .supported());
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # CreateCertificateSigningRequest operation
+//!
+//! Create a PKCS#10 certificate signing request (CSR) for the public part of a key pair. The
+//! request is signed with the private key inside the service.
+
+use super::psa_key_attributes::{Attributes, Type};
+use super::psa_sign_message::validate_message_signing;
+use crate::operations::psa_algorithm::AsymmetricSignature;
+use crate::requests::ResponseStatus;
+use std::collections::HashSet;
+
+/// An attribute of the subject distinguished name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NameAttribute {
+    /// Object identifier of the attribute type in dotted decimal form, for example `2.5.4.3` for
+    /// the common name.
+    pub oid: String,
+    /// Value of the attribute, encoded as a UTF8String.
+    pub value: String,
+}
+
+/// An extension requested for the certificate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Extension {
+    /// Object identifier of the extension in dotted decimal form.
+    pub oid: String,
+    /// `true` if the extension is critical.
+    pub critical: bool,
+    /// DER encoding of the value of the extension.
+    pub value: Vec<u8>,
+}
+
+/// Native object for certificate signing request creation operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key pair the request is created for and signed with.
+    pub key_name: String,
+    /// Attributes of the subject distinguished name, in order.
+    pub subject: Vec<NameAttribute>,
+    /// Extensions requested for the certificate, added to the request as an
+    /// `extensionRequest` attribute.
+    pub extensions: Vec<Extension>,
+    /// An asymmetric signature algorithm that is compatible with the type of key. The request is
+    /// hashed with the hash algorithm it specifies.
+    pub alg: AsymmetricSignature,
+}
+
+/// Native object for certificate signing request creation result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `csr` field contains the DER encoding of the signed request.
+    pub csr: Vec<u8>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key can sign messages with the requested algorithm, as `PsaSignMessage` checks it
+    /// * the key is a key pair
+    /// * the object identifiers of the subject attributes and extensions are well-formed
+    /// * no extension is given more than once
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        validate_message_signing(self.alg, key_attributes)?;
+        if !matches!(
+            key_attributes.key_type,
+            Type::RsaKeyPair | Type::EccKeyPair { .. }
+        ) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if !self
+            .subject
+            .iter()
+            .all(|attribute| is_valid_oid(&attribute.oid))
+        {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        let mut extension_oids = HashSet::new();
+        for extension in &self.extensions {
+            if !is_valid_oid(&extension.oid) || !extension_oids.insert(extension.oid.as_str()) {
+                return Err(ResponseStatus::PsaErrorInvalidArgument);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check if `oid` is an object identifier in dotted decimal form.
+///
+/// It must have at least two arcs, the first one being 0, 1 or 2, and the second one being lower
+/// than 40 when the first one is 0 or 1.
+fn is_valid_oid(oid: &str) -> bool {
+    let arcs: Option<Vec<u64>> = oid
+        .split('.')
+        .map(|arc| {
+            if arc.is_empty() || !arc.bytes().all(|c| c.is_ascii_digit()) {
+                None
+            } else {
+                arc.parse().ok()
+            }
+        })
+        .collect();
+    match arcs.as_deref() {
+        Some([first, second, ..]) => *first == 2 || (*first < 2 && *second < 40),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, Hash, SignHash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: true,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: SignHash::Specific(Hash::Sha256),
+                }),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            subject: vec![NameAttribute {
+                oid: String::from("2.5.4.3"),
+                value: String::from("device-0001"),
+            }],
+            extensions: vec![Extension {
+                oid: String::from("2.5.29.15"),
+                critical: true,
+                value: vec![0x03, 0x02, 0x07, 0x80],
+            }],
+            alg: AsymmetricSignature::Ecdsa {
+                hash_alg: SignHash::Specific(Hash::Sha256),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op().validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_sign() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.sign_hash = false;
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let mut op = get_op();
+        op.alg = AsymmetricSignature::Ecdsa {
+            hash_alg: SignHash::Specific(Hash::Sha384),
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn no_specific_hash() {
+        let mut op = get_op();
+        op.alg = AsymmetricSignature::Ecdsa {
+            hash_alg: SignHash::Any,
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn public_key() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        };
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_oid() {
+        let mut op = get_op();
+        op.subject[0].oid = String::from("2.5.4.");
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn duplicate_extension() {
+        let mut op = get_op();
+        op.extensions.push(op.extensions[0].clone());
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn oids() {
+        assert!(is_valid_oid("2.5.4.3"));
+        assert!(is_valid_oid("1.2.840.10045.4.3.2"));
+        assert!(is_valid_oid("2.999"));
+        assert!(!is_valid_oid("2"));
+        assert!(!is_valid_oid("3.1"));
+        assert!(!is_valid_oid("1.40"));
+        assert!(!is_valid_oid("1..2"));
+        assert!(!is_valid_oid("1.+2"));
+        assert!(!is_valid_oid(""));
+    }
+}
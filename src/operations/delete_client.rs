@@ -0,0 +1,19 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # DeleteClient operation
+//!
+//! Delete all keys owned by a client. This is an admin operation, to be sent to the core
+//! provider.
+
+/// Native object for client deletion operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// `client` is the name of the client application whose keys are deleted.
+    pub client: String,
+}
+
+/// Native object for client deletion result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
@@ -0,0 +1,97 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Key attestation
+//!
+//! Types shared by the `PrepareKeyAttestation` and `AttestKey` operations.
+
+use super::psa_key_attributes::{Attributes, Type};
+use crate::requests::{ResponseStatus, Result};
+
+/// Mechanism used to produce the attestation token of a key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AttestationMechanism {
+    /// The key is certified by an attesting key of a TPM 2.0 with the `TPM2_Certify` command. The
+    /// token contains the marshalled `TPMS_ATTEST` structure followed by its signature.
+    Tpm2Certify,
+    /// A mechanism specific to a vendor, identified by a number defined by the vendor.
+    VendorSpecific(u32),
+}
+
+impl AttestationMechanism {
+    /// Maximum length in bytes of the nonce accepted by the mechanism, if it has one.
+    pub fn max_nonce_length(self) -> Option<usize> {
+        match self {
+            // The nonce is the qualifying data of the command, a TPM2B_DATA holding at most
+            // a SHA-512 digest.
+            AttestationMechanism::Tpm2Certify => Some(64),
+            AttestationMechanism::VendorSpecific(_) => None,
+        }
+    }
+}
+
+/// Check that the attributes of the key to attest are the ones of a key pair.
+///
+/// Only keys generated or imported with their private part can be attested. Providers should
+/// call this function with the attributes of the attested key, once they made sure it exists.
+/// `PsaErrorInvalidArgument` is returned if it is not a key pair.
+pub fn validate_attested_key(attributes: Attributes) -> Result<()> {
+    if !matches!(
+        attributes.key_type,
+        Type::RsaKeyPair | Type::EccKeyPair { .. } | Type::DhKeyPair { .. }
+    ) {
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash, SignHash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: true,
+                    verify_message: false,
+                    sign_hash: true,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: SignHash::Specific(Hash::Sha256),
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn key_pair() {
+        validate_attested_key(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn public_key() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::EccPublicKey {
+            curve_family: EccFamily::SecpR1,
+        };
+        assert_eq!(
+            validate_attested_key(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # ListClients operation
+//!
+//! Lists all clients owning keys in the service. This is an admin operation, to be sent to the
+//! core provider.
+
+/// Native object for client listing operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation;
+
+/// Native object for client listing result.
+#[derive(Debug)]
+pub struct Result {
+    /// A list of client application names.
+    pub clients: Vec<String>,
+}
@@ -0,0 +1,170 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # ListKeys operation
+//!
+//! Lists all keys belonging to the application, optionally filtered.
+use super::psa_key_attributes::{Attributes, Type, UsageFlags};
+use crate::requests::ProviderID;
+
+/// Structure holding the basic information for a key in the application for client discovery.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyInfo {
+    /// The ID of the associated provider.
+    pub provider_id: ProviderID,
+    /// The name of the key.
+    pub name: String,
+    /// The key attributes.
+    pub attributes: Attributes,
+}
+
+/// Native object for key listing operation.
+///
+/// Each filter that is set restricts the listing to the keys matching it. Keys are listed
+/// regardless of a filter that is not set.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Operation {
+    /// Only list the keys stored in this provider.
+    pub provider_id: Option<ProviderID>,
+    /// Only list the keys of this type.
+    pub key_type: Option<Type>,
+    /// Only list the keys whose policy allows, at least, all of these usages.
+    pub usage_flags: Option<UsageFlags>,
+}
+
+/// Native object for key listing result.
+#[derive(Debug)]
+pub struct Result {
+    /// A list of `KeyInfo` structures, one for each key matching the filters of the operation.
+    pub keys: Vec<KeyInfo>,
+}
+
+impl Operation {
+    /// Check if a key matches all the filters of the operation.
+    pub fn matches(&self, key: &KeyInfo) -> bool {
+        if let Some(provider_id) = self.provider_id {
+            if key.provider_id != provider_id {
+                return false;
+            }
+        }
+        if let Some(key_type) = self.key_type {
+            if key.attributes.key_type != key_type {
+                return false;
+            }
+        }
+        if let Some(usage_flags) = self.usage_flags {
+            if !allows_usages(key.attributes.policy.usage_flags, usage_flags) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Check if all the usages set in `requested` are also set in `allowed`.
+fn allows_usages(allowed: UsageFlags, requested: UsageFlags) -> bool {
+    let flags = |flags: UsageFlags| {
+        [
+            flags.export,
+            flags.copy,
+            flags.cache,
+            flags.encrypt,
+            flags.decrypt,
+            flags.sign_message,
+            flags.verify_message,
+            flags.sign_hash,
+            flags.verify_hash,
+            flags.derive,
+        ]
+    };
+    flags(allowed)
+        .iter()
+        .zip(flags(requested).iter())
+        .all(|(allowed, requested)| *allowed || !*requested)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{Lifetime, Policy};
+
+    fn get_usage_flags() -> UsageFlags {
+        UsageFlags {
+            export: false,
+            copy: false,
+            cache: false,
+            encrypt: false,
+            decrypt: false,
+            sign_message: false,
+            verify_message: false,
+            sign_hash: false,
+            verify_hash: false,
+            derive: false,
+        }
+    }
+
+    fn get_key_info() -> KeyInfo {
+        let mut usage_flags = get_usage_flags();
+        usage_flags.sign_hash = true;
+        usage_flags.verify_hash = true;
+        KeyInfo {
+            provider_id: ProviderID::MbedCrypto,
+            name: String::from("some key"),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::RsaKeyPair,
+                bits: 1024,
+                policy: Policy {
+                    usage_flags,
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::RsaPkcs1v15Sign {
+                            hash_alg: Hash::Sha256.into(),
+                        },
+                    ),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn no_filter_matches() {
+        assert!(Operation::default().matches(&get_key_info()));
+    }
+
+    #[test]
+    fn provider_filter() {
+        let mut op = Operation {
+            provider_id: Some(ProviderID::MbedCrypto),
+            ..Default::default()
+        };
+        assert!(op.matches(&get_key_info()));
+        op.provider_id = Some(ProviderID::Tpm);
+        assert!(!op.matches(&get_key_info()));
+    }
+
+    #[test]
+    fn key_type_filter() {
+        let mut op = Operation {
+            key_type: Some(Type::RsaKeyPair),
+            ..Default::default()
+        };
+        assert!(op.matches(&get_key_info()));
+        op.key_type = Some(Type::RsaPublicKey);
+        assert!(!op.matches(&get_key_info()));
+    }
+
+    #[test]
+    fn usage_flags_filter() {
+        let mut usage_flags = get_usage_flags();
+        usage_flags.sign_hash = true;
+        let mut op = Operation {
+            usage_flags: Some(usage_flags),
+            ..Default::default()
+        };
+        assert!(op.matches(&get_key_info()));
+        usage_flags.export = true;
+        op.usage_flags = Some(usage_flags);
+        assert!(!op.matches(&get_key_info()));
+    }
+}
@@ -18,11 +18,61 @@ pub mod psa_sign_hash;
 pub mod psa_verify_hash;
 pub mod list_opcodes;
 pub mod list_providers;
+pub mod psa_hash_compute;
+pub mod psa_hash_compare;
+pub mod psa_mac_compute;
+pub mod psa_mac_verify;
+pub mod psa_cipher_encrypt;
+pub mod psa_cipher_decrypt;
+pub mod psa_aead_encrypt;
+pub mod psa_aead_decrypt;
+pub mod psa_asymmetric_encrypt;
+pub mod psa_asymmetric_decrypt;
+pub mod psa_raw_key_agreement;
+pub mod psa_generate_random;
+pub mod psa_export_key;
+pub mod psa_get_key_attributes;
+pub mod list_keys;
+pub mod list_authenticators;
+pub mod list_clients;
+pub mod delete_client;
+pub mod psa_copy_key;
+pub mod psa_sign_message;
+pub mod psa_verify_message;
+pub mod psa_key_derivation;
+pub mod psa_key_derivation_setup;
+pub mod psa_key_derivation_input_bytes;
+pub mod psa_key_derivation_input_key;
+pub mod psa_key_derivation_output_bytes;
+pub mod psa_key_derivation_output_key;
+pub mod psa_hash_setup;
+pub mod psa_hash_update;
+pub mod psa_hash_finish;
+pub mod psa_hash_abort;
+pub mod psa_cipher_encrypt_setup;
+pub mod psa_cipher_decrypt_setup;
+pub mod psa_cipher_update;
+pub mod psa_cipher_finish;
+pub mod psa_cipher_abort;
+pub mod psa_aead_encrypt_setup;
+pub mod psa_aead_decrypt_setup;
+pub mod psa_aead_update;
+pub mod psa_aead_finish;
+pub mod psa_aead_verify;
+pub mod psa_aead_abort;
+pub mod can_do_crypto;
+pub mod key_attestation;
+pub mod prepare_key_attestation;
+pub mod attest_key;
+pub mod create_certificate_signing_request;
 
 pub use psa_crypto::types::algorithm as psa_algorithm;
 pub use psa_crypto::types::key as psa_key_attributes;
 
-use crate::requests::{request::RequestBody, response::ResponseBody, BodyType, Opcode, Result};
+use crate::requests::{
+    request::RequestBody, response::ResponseBody, BodyType, Opcode, ResponseStatus, Result,
+};
+use psa_key_attributes::Attributes;
 
 /// Container type for operation conversion values, holding a native operation object
 /// to be passed in/out of a converter.
@@ -46,6 +96,96 @@ pub enum NativeOperation {
     PsaSignHash(psa_sign_hash::Operation),
     /// PsaVerifyHash operation
     PsaVerifyHash(psa_verify_hash::Operation),
+    /// PsaHashCompute operation
+    PsaHashCompute(psa_hash_compute::Operation),
+    /// PsaHashCompare operation
+    PsaHashCompare(psa_hash_compare::Operation),
+    /// PsaMacCompute operation
+    PsaMacCompute(psa_mac_compute::Operation),
+    /// PsaMacVerify operation
+    PsaMacVerify(psa_mac_verify::Operation),
+    /// PsaCipherEncrypt operation
+    PsaCipherEncrypt(psa_cipher_encrypt::Operation),
+    /// PsaCipherDecrypt operation
+    PsaCipherDecrypt(psa_cipher_decrypt::Operation),
+    /// PsaAeadEncrypt operation
+    PsaAeadEncrypt(psa_aead_encrypt::Operation),
+    /// PsaAeadDecrypt operation
+    PsaAeadDecrypt(psa_aead_decrypt::Operation),
+    /// PsaAsymmetricEncrypt operation
+    PsaAsymmetricEncrypt(psa_asymmetric_encrypt::Operation),
+    /// PsaAsymmetricDecrypt operation
+    PsaAsymmetricDecrypt(psa_asymmetric_decrypt::Operation),
+    /// PsaRawKeyAgreement operation
+    PsaRawKeyAgreement(psa_raw_key_agreement::Operation),
+    /// PsaGenerateRandom operation
+    PsaGenerateRandom(psa_generate_random::Operation),
+    /// PsaExportKey operation
+    PsaExportKey(psa_export_key::Operation),
+    /// PsaGetKeyAttributes operation
+    PsaGetKeyAttributes(psa_get_key_attributes::Operation),
+    /// ListKeys operation
+    ListKeys(list_keys::Operation),
+    /// ListAuthenticators operation
+    ListAuthenticators(list_authenticators::Operation),
+    /// ListClients operation
+    ListClients(list_clients::Operation),
+    /// DeleteClient operation
+    DeleteClient(delete_client::Operation),
+    /// PsaCopyKey operation
+    PsaCopyKey(psa_copy_key::Operation),
+    /// PsaSignMessage operation
+    PsaSignMessage(psa_sign_message::Operation),
+    /// PsaVerifyMessage operation
+    PsaVerifyMessage(psa_verify_message::Operation),
+    /// PsaKeyDerivationSetup operation
+    PsaKeyDerivationSetup(psa_key_derivation_setup::Operation),
+    /// PsaKeyDerivationInputBytes operation
+    PsaKeyDerivationInputBytes(psa_key_derivation_input_bytes::Operation),
+    /// PsaKeyDerivationInputKey operation
+    PsaKeyDerivationInputKey(psa_key_derivation_input_key::Operation),
+    /// PsaKeyDerivationOutputBytes operation
+    PsaKeyDerivationOutputBytes(psa_key_derivation_output_bytes::Operation),
+    /// PsaKeyDerivationOutputKey operation
+    PsaKeyDerivationOutputKey(psa_key_derivation_output_key::Operation),
+    /// PsaHashSetup operation
+    PsaHashSetup(psa_hash_setup::Operation),
+    /// PsaHashUpdate operation
+    PsaHashUpdate(psa_hash_update::Operation),
+    /// PsaHashFinish operation
+    PsaHashFinish(psa_hash_finish::Operation),
+    /// PsaHashAbort operation
+    PsaHashAbort(psa_hash_abort::Operation),
+    /// PsaCipherEncryptSetup operation
+    PsaCipherEncryptSetup(psa_cipher_encrypt_setup::Operation),
+    /// PsaCipherDecryptSetup operation
+    PsaCipherDecryptSetup(psa_cipher_decrypt_setup::Operation),
+    /// PsaCipherUpdate operation
+    PsaCipherUpdate(psa_cipher_update::Operation),
+    /// PsaCipherFinish operation
+    PsaCipherFinish(psa_cipher_finish::Operation),
+    /// PsaCipherAbort operation
+    PsaCipherAbort(psa_cipher_abort::Operation),
+    /// PsaAeadEncryptSetup operation
+    PsaAeadEncryptSetup(psa_aead_encrypt_setup::Operation),
+    /// PsaAeadDecryptSetup operation
+    PsaAeadDecryptSetup(psa_aead_decrypt_setup::Operation),
+    /// PsaAeadUpdate operation
+    PsaAeadUpdate(psa_aead_update::Operation),
+    /// PsaAeadFinish operation
+    PsaAeadFinish(psa_aead_finish::Operation),
+    /// PsaAeadVerify operation
+    PsaAeadVerify(psa_aead_verify::Operation),
+    /// PsaAeadAbort operation
+    PsaAeadAbort(psa_aead_abort::Operation),
+    /// CanDoCrypto operation
+    CanDoCrypto(can_do_crypto::Operation),
+    /// PrepareKeyAttestation operation
+    PrepareKeyAttestation(prepare_key_attestation::Operation),
+    /// AttestKey operation
+    AttestKey(attest_key::Operation),
+    /// CreateCertificateSigningRequest operation
+    CreateCertificateSigningRequest(create_certificate_signing_request::Operation),
 }
 
 impl NativeOperation {
@@ -61,6 +201,53 @@ impl NativeOperation {
             NativeOperation::PsaExportPublicKey(_) => Opcode::PsaExportPublicKey,
             NativeOperation::ListOpcodes(_) => Opcode::ListOpcodes,
             NativeOperation::ListProviders(_) => Opcode::ListProviders,
+            NativeOperation::PsaHashCompute(_) => Opcode::PsaHashCompute,
+            NativeOperation::PsaHashCompare(_) => Opcode::PsaHashCompare,
+            NativeOperation::PsaMacCompute(_) => Opcode::PsaMacCompute,
+            NativeOperation::PsaMacVerify(_) => Opcode::PsaMacVerify,
+            NativeOperation::PsaCipherEncrypt(_) => Opcode::PsaCipherEncrypt,
+            NativeOperation::PsaCipherDecrypt(_) => Opcode::PsaCipherDecrypt,
+            NativeOperation::PsaAeadEncrypt(_) => Opcode::PsaAeadEncrypt,
+            NativeOperation::PsaAeadDecrypt(_) => Opcode::PsaAeadDecrypt,
+            NativeOperation::PsaAsymmetricEncrypt(_) => Opcode::PsaAsymmetricEncrypt,
+            NativeOperation::PsaAsymmetricDecrypt(_) => Opcode::PsaAsymmetricDecrypt,
+            NativeOperation::PsaRawKeyAgreement(_) => Opcode::PsaRawKeyAgreement,
+            NativeOperation::PsaGenerateRandom(_) => Opcode::PsaGenerateRandom,
+            NativeOperation::PsaExportKey(_) => Opcode::PsaExportKey,
+            NativeOperation::PsaGetKeyAttributes(_) => Opcode::PsaGetKeyAttributes,
+            NativeOperation::ListKeys(_) => Opcode::ListKeys,
+            NativeOperation::ListAuthenticators(_) => Opcode::ListAuthenticators,
+            NativeOperation::ListClients(_) => Opcode::ListClients,
+            NativeOperation::DeleteClient(_) => Opcode::DeleteClient,
+            NativeOperation::PsaCopyKey(_) => Opcode::PsaCopyKey,
+            NativeOperation::PsaSignMessage(_) => Opcode::PsaSignMessage,
+            NativeOperation::PsaVerifyMessage(_) => Opcode::PsaVerifyMessage,
+            NativeOperation::PsaKeyDerivationSetup(_) => Opcode::PsaKeyDerivationSetup,
+            NativeOperation::PsaKeyDerivationInputBytes(_) => Opcode::PsaKeyDerivationInputBytes,
+            NativeOperation::PsaKeyDerivationInputKey(_) => Opcode::PsaKeyDerivationInputKey,
+            NativeOperation::PsaKeyDerivationOutputBytes(_) => Opcode::PsaKeyDerivationOutputBytes,
+            NativeOperation::PsaKeyDerivationOutputKey(_) => Opcode::PsaKeyDerivationOutputKey,
+            NativeOperation::PsaHashSetup(_) => Opcode::PsaHashSetup,
+            NativeOperation::PsaHashUpdate(_) => Opcode::PsaHashUpdate,
+            NativeOperation::PsaHashFinish(_) => Opcode::PsaHashFinish,
+            NativeOperation::PsaHashAbort(_) => Opcode::PsaHashAbort,
+            NativeOperation::PsaCipherEncryptSetup(_) => Opcode::PsaCipherEncryptSetup,
+            NativeOperation::PsaCipherDecryptSetup(_) => Opcode::PsaCipherDecryptSetup,
+            NativeOperation::PsaCipherUpdate(_) => Opcode::PsaCipherUpdate,
+            NativeOperation::PsaCipherFinish(_) => Opcode::PsaCipherFinish,
+            NativeOperation::PsaCipherAbort(_) => Opcode::PsaCipherAbort,
+            NativeOperation::PsaAeadEncryptSetup(_) => Opcode::PsaAeadEncryptSetup,
+            NativeOperation::PsaAeadDecryptSetup(_) => Opcode::PsaAeadDecryptSetup,
+            NativeOperation::PsaAeadUpdate(_) => Opcode::PsaAeadUpdate,
+            NativeOperation::PsaAeadFinish(_) => Opcode::PsaAeadFinish,
+            NativeOperation::PsaAeadVerify(_) => Opcode::PsaAeadVerify,
+            NativeOperation::PsaAeadAbort(_) => Opcode::PsaAeadAbort,
+            NativeOperation::CanDoCrypto(_) => Opcode::CanDoCrypto,
+            NativeOperation::PrepareKeyAttestation(_) => Opcode::PrepareKeyAttestation,
+            NativeOperation::AttestKey(_) => Opcode::AttestKey,
+            NativeOperation::CreateCertificateSigningRequest(_) => {
+                Opcode::CreateCertificateSigningRequest
+            }
         }
     }
 }
@@ -87,6 +274,96 @@ pub enum NativeResult {
     PsaSignHash(psa_sign_hash::Result),
     /// PsaVerifyHash result
     PsaVerifyHash(psa_verify_hash::Result),
+    /// PsaHashCompute result
+    PsaHashCompute(psa_hash_compute::Result),
+    /// PsaHashCompare result
+    PsaHashCompare(psa_hash_compare::Result),
+    /// PsaMacCompute result
+    PsaMacCompute(psa_mac_compute::Result),
+    /// PsaMacVerify result
+    PsaMacVerify(psa_mac_verify::Result),
+    /// PsaCipherEncrypt result
+    PsaCipherEncrypt(psa_cipher_encrypt::Result),
+    /// PsaCipherDecrypt result
+    PsaCipherDecrypt(psa_cipher_decrypt::Result),
+    /// PsaAeadEncrypt result
+    PsaAeadEncrypt(psa_aead_encrypt::Result),
+    /// PsaAeadDecrypt result
+    PsaAeadDecrypt(psa_aead_decrypt::Result),
+    /// PsaAsymmetricEncrypt result
+    PsaAsymmetricEncrypt(psa_asymmetric_encrypt::Result),
+    /// PsaAsymmetricDecrypt result
+    PsaAsymmetricDecrypt(psa_asymmetric_decrypt::Result),
+    /// PsaRawKeyAgreement result
+    PsaRawKeyAgreement(psa_raw_key_agreement::Result),
+    /// PsaGenerateRandom result
+    PsaGenerateRandom(psa_generate_random::Result),
+    /// PsaExportKey result
+    PsaExportKey(psa_export_key::Result),
+    /// PsaGetKeyAttributes result
+    PsaGetKeyAttributes(psa_get_key_attributes::Result),
+    /// ListKeys result
+    ListKeys(list_keys::Result),
+    /// ListAuthenticators result
+    ListAuthenticators(list_authenticators::Result),
+    /// ListClients result
+    ListClients(list_clients::Result),
+    /// DeleteClient result
+    DeleteClient(delete_client::Result),
+    /// PsaCopyKey result
+    PsaCopyKey(psa_copy_key::Result),
+    /// PsaSignMessage result
+    PsaSignMessage(psa_sign_message::Result),
+    /// PsaVerifyMessage result
+    PsaVerifyMessage(psa_verify_message::Result),
+    /// PsaKeyDerivationSetup result
+    PsaKeyDerivationSetup(psa_key_derivation_setup::Result),
+    /// PsaKeyDerivationInputBytes result
+    PsaKeyDerivationInputBytes(psa_key_derivation_input_bytes::Result),
+    /// PsaKeyDerivationInputKey result
+    PsaKeyDerivationInputKey(psa_key_derivation_input_key::Result),
+    /// PsaKeyDerivationOutputBytes result
+    PsaKeyDerivationOutputBytes(psa_key_derivation_output_bytes::Result),
+    /// PsaKeyDerivationOutputKey result
+    PsaKeyDerivationOutputKey(psa_key_derivation_output_key::Result),
+    /// PsaHashSetup result
+    PsaHashSetup(psa_hash_setup::Result),
+    /// PsaHashUpdate result
+    PsaHashUpdate(psa_hash_update::Result),
+    /// PsaHashFinish result
+    PsaHashFinish(psa_hash_finish::Result),
+    /// PsaHashAbort result
+    PsaHashAbort(psa_hash_abort::Result),
+    /// PsaCipherEncryptSetup result
+    PsaCipherEncryptSetup(psa_cipher_encrypt_setup::Result),
+    /// PsaCipherDecryptSetup result
+    PsaCipherDecryptSetup(psa_cipher_decrypt_setup::Result),
+    /// PsaCipherUpdate result
+    PsaCipherUpdate(psa_cipher_update::Result),
+    /// PsaCipherFinish result
+    PsaCipherFinish(psa_cipher_finish::Result),
+    /// PsaCipherAbort result
+    PsaCipherAbort(psa_cipher_abort::Result),
+    /// PsaAeadEncryptSetup result
+    PsaAeadEncryptSetup(psa_aead_encrypt_setup::Result),
+    /// PsaAeadDecryptSetup result
+    PsaAeadDecryptSetup(psa_aead_decrypt_setup::Result),
+    /// PsaAeadUpdate result
+    PsaAeadUpdate(psa_aead_update::Result),
+    /// PsaAeadFinish result
+    PsaAeadFinish(psa_aead_finish::Result),
+    /// PsaAeadVerify result
+    PsaAeadVerify(psa_aead_verify::Result),
+    /// PsaAeadAbort result
+    PsaAeadAbort(psa_aead_abort::Result),
+    /// CanDoCrypto result
+    CanDoCrypto(can_do_crypto::Result),
+    /// PrepareKeyAttestation result
+    PrepareKeyAttestation(prepare_key_attestation::Result),
+    /// AttestKey result
+    AttestKey(attest_key::Result),
+    /// CreateCertificateSigningRequest result
+    CreateCertificateSigningRequest(create_certificate_signing_request::Result),
 }
 
 impl NativeResult {
@@ -102,6 +379,53 @@ impl NativeResult {
             NativeResult::PsaExportPublicKey(_) => Opcode::PsaExportPublicKey,
             NativeResult::ListOpcodes(_) => Opcode::ListOpcodes,
             NativeResult::ListProviders(_) => Opcode::ListProviders,
+            NativeResult::PsaHashCompute(_) => Opcode::PsaHashCompute,
+            NativeResult::PsaHashCompare(_) => Opcode::PsaHashCompare,
+            NativeResult::PsaMacCompute(_) => Opcode::PsaMacCompute,
+            NativeResult::PsaMacVerify(_) => Opcode::PsaMacVerify,
+            NativeResult::PsaCipherEncrypt(_) => Opcode::PsaCipherEncrypt,
+            NativeResult::PsaCipherDecrypt(_) => Opcode::PsaCipherDecrypt,
+            NativeResult::PsaAeadEncrypt(_) => Opcode::PsaAeadEncrypt,
+            NativeResult::PsaAeadDecrypt(_) => Opcode::PsaAeadDecrypt,
+            NativeResult::PsaAsymmetricEncrypt(_) => Opcode::PsaAsymmetricEncrypt,
+            NativeResult::PsaAsymmetricDecrypt(_) => Opcode::PsaAsymmetricDecrypt,
+            NativeResult::PsaRawKeyAgreement(_) => Opcode::PsaRawKeyAgreement,
+            NativeResult::PsaGenerateRandom(_) => Opcode::PsaGenerateRandom,
+            NativeResult::PsaExportKey(_) => Opcode::PsaExportKey,
+            NativeResult::PsaGetKeyAttributes(_) => Opcode::PsaGetKeyAttributes,
+            NativeResult::ListKeys(_) => Opcode::ListKeys,
+            NativeResult::ListAuthenticators(_) => Opcode::ListAuthenticators,
+            NativeResult::ListClients(_) => Opcode::ListClients,
+            NativeResult::DeleteClient(_) => Opcode::DeleteClient,
+            NativeResult::PsaCopyKey(_) => Opcode::PsaCopyKey,
+            NativeResult::PsaSignMessage(_) => Opcode::PsaSignMessage,
+            NativeResult::PsaVerifyMessage(_) => Opcode::PsaVerifyMessage,
+            NativeResult::PsaKeyDerivationSetup(_) => Opcode::PsaKeyDerivationSetup,
+            NativeResult::PsaKeyDerivationInputBytes(_) => Opcode::PsaKeyDerivationInputBytes,
+            NativeResult::PsaKeyDerivationInputKey(_) => Opcode::PsaKeyDerivationInputKey,
+            NativeResult::PsaKeyDerivationOutputBytes(_) => Opcode::PsaKeyDerivationOutputBytes,
+            NativeResult::PsaKeyDerivationOutputKey(_) => Opcode::PsaKeyDerivationOutputKey,
+            NativeResult::PsaHashSetup(_) => Opcode::PsaHashSetup,
+            NativeResult::PsaHashUpdate(_) => Opcode::PsaHashUpdate,
+            NativeResult::PsaHashFinish(_) => Opcode::PsaHashFinish,
+            NativeResult::PsaHashAbort(_) => Opcode::PsaHashAbort,
+            NativeResult::PsaCipherEncryptSetup(_) => Opcode::PsaCipherEncryptSetup,
+            NativeResult::PsaCipherDecryptSetup(_) => Opcode::PsaCipherDecryptSetup,
+            NativeResult::PsaCipherUpdate(_) => Opcode::PsaCipherUpdate,
+            NativeResult::PsaCipherFinish(_) => Opcode::PsaCipherFinish,
+            NativeResult::PsaCipherAbort(_) => Opcode::PsaCipherAbort,
+            NativeResult::PsaAeadEncryptSetup(_) => Opcode::PsaAeadEncryptSetup,
+            NativeResult::PsaAeadDecryptSetup(_) => Opcode::PsaAeadDecryptSetup,
+            NativeResult::PsaAeadUpdate(_) => Opcode::PsaAeadUpdate,
+            NativeResult::PsaAeadFinish(_) => Opcode::PsaAeadFinish,
+            NativeResult::PsaAeadVerify(_) => Opcode::PsaAeadVerify,
+            NativeResult::PsaAeadAbort(_) => Opcode::PsaAeadAbort,
+            NativeResult::CanDoCrypto(_) => Opcode::CanDoCrypto,
+            NativeResult::PrepareKeyAttestation(_) => Opcode::PrepareKeyAttestation,
+            NativeResult::AttestKey(_) => Opcode::AttestKey,
+            NativeResult::CreateCertificateSigningRequest(_) => {
+                Opcode::CreateCertificateSigningRequest
+            }
         }
     }
 }
@@ -137,6 +461,32 @@ pub trait Convert {
     fn result_to_body(&self, result: NativeResult) -> Result<ResponseBody>;
 }
 
+/// Check if the policy of a key allows signing messages, with a MAC or an asymmetric signature.
+///
+/// As of version 1.0.1 of the PSA Crypto API, permission to sign hashes implies permission to
+/// sign messages. `PsaErrorNotPermitted` is returned if neither usage flag is set.
+pub(crate) fn can_sign_message(attributes: Attributes) -> Result<()> {
+    let usage_flags = attributes.policy.usage_flags;
+    if !(usage_flags.sign_message || usage_flags.sign_hash) {
+        return Err(ResponseStatus::PsaErrorNotPermitted);
+    }
+
+    Ok(())
+}
+
+/// Check if the policy of a key allows verifying messages, with a MAC or an asymmetric signature.
+///
+/// Permission to verify hashes implies permission to verify messages, as for
+/// [`can_sign_message`].
+pub(crate) fn can_verify_message(attributes: Attributes) -> Result<()> {
+    let usage_flags = attributes.policy.usage_flags;
+    if !(usage_flags.verify_message || usage_flags.verify_hash) {
+        return Err(ResponseStatus::PsaErrorNotPermitted);
+    }
+
+    Ok(())
+}
+
 impl From<list_providers::Operation> for NativeOperation {
     fn from(op: list_providers::Operation) -> Self {
         NativeOperation::ListProviders(op)
@@ -191,6 +541,276 @@ impl From<psa_verify_hash::Operation> for NativeOperation {
     }
 }
 
+impl From<psa_hash_compute::Operation> for NativeOperation {
+    fn from(op: psa_hash_compute::Operation) -> Self {
+        NativeOperation::PsaHashCompute(op)
+    }
+}
+
+impl From<psa_hash_compare::Operation> for NativeOperation {
+    fn from(op: psa_hash_compare::Operation) -> Self {
+        NativeOperation::PsaHashCompare(op)
+    }
+}
+
+impl From<psa_mac_compute::Operation> for NativeOperation {
+    fn from(op: psa_mac_compute::Operation) -> Self {
+        NativeOperation::PsaMacCompute(op)
+    }
+}
+
+impl From<psa_mac_verify::Operation> for NativeOperation {
+    fn from(op: psa_mac_verify::Operation) -> Self {
+        NativeOperation::PsaMacVerify(op)
+    }
+}
+
+impl From<psa_cipher_encrypt::Operation> for NativeOperation {
+    fn from(op: psa_cipher_encrypt::Operation) -> Self {
+        NativeOperation::PsaCipherEncrypt(op)
+    }
+}
+
+impl From<psa_cipher_decrypt::Operation> for NativeOperation {
+    fn from(op: psa_cipher_decrypt::Operation) -> Self {
+        NativeOperation::PsaCipherDecrypt(op)
+    }
+}
+
+impl From<psa_aead_encrypt::Operation> for NativeOperation {
+    fn from(op: psa_aead_encrypt::Operation) -> Self {
+        NativeOperation::PsaAeadEncrypt(op)
+    }
+}
+
+impl From<psa_aead_decrypt::Operation> for NativeOperation {
+    fn from(op: psa_aead_decrypt::Operation) -> Self {
+        NativeOperation::PsaAeadDecrypt(op)
+    }
+}
+
+impl From<psa_asymmetric_encrypt::Operation> for NativeOperation {
+    fn from(op: psa_asymmetric_encrypt::Operation) -> Self {
+        NativeOperation::PsaAsymmetricEncrypt(op)
+    }
+}
+
+impl From<psa_asymmetric_decrypt::Operation> for NativeOperation {
+    fn from(op: psa_asymmetric_decrypt::Operation) -> Self {
+        NativeOperation::PsaAsymmetricDecrypt(op)
+    }
+}
+
+impl From<psa_raw_key_agreement::Operation> for NativeOperation {
+    fn from(op: psa_raw_key_agreement::Operation) -> Self {
+        NativeOperation::PsaRawKeyAgreement(op)
+    }
+}
+
+impl From<psa_generate_random::Operation> for NativeOperation {
+    fn from(op: psa_generate_random::Operation) -> Self {
+        NativeOperation::PsaGenerateRandom(op)
+    }
+}
+
+impl From<psa_export_key::Operation> for NativeOperation {
+    fn from(op: psa_export_key::Operation) -> Self {
+        NativeOperation::PsaExportKey(op)
+    }
+}
+
+impl From<psa_get_key_attributes::Operation> for NativeOperation {
+    fn from(op: psa_get_key_attributes::Operation) -> Self {
+        NativeOperation::PsaGetKeyAttributes(op)
+    }
+}
+
+impl From<list_keys::Operation> for NativeOperation {
+    fn from(op: list_keys::Operation) -> Self {
+        NativeOperation::ListKeys(op)
+    }
+}
+
+impl From<list_authenticators::Operation> for NativeOperation {
+    fn from(op: list_authenticators::Operation) -> Self {
+        NativeOperation::ListAuthenticators(op)
+    }
+}
+
+impl From<list_clients::Operation> for NativeOperation {
+    fn from(op: list_clients::Operation) -> Self {
+        NativeOperation::ListClients(op)
+    }
+}
+
+impl From<delete_client::Operation> for NativeOperation {
+    fn from(op: delete_client::Operation) -> Self {
+        NativeOperation::DeleteClient(op)
+    }
+}
+
+impl From<psa_copy_key::Operation> for NativeOperation {
+    fn from(op: psa_copy_key::Operation) -> Self {
+        NativeOperation::PsaCopyKey(op)
+    }
+}
+
+impl From<psa_sign_message::Operation> for NativeOperation {
+    fn from(op: psa_sign_message::Operation) -> Self {
+        NativeOperation::PsaSignMessage(op)
+    }
+}
+
+impl From<psa_verify_message::Operation> for NativeOperation {
+    fn from(op: psa_verify_message::Operation) -> Self {
+        NativeOperation::PsaVerifyMessage(op)
+    }
+}
+
+impl From<psa_key_derivation_setup::Operation> for NativeOperation {
+    fn from(op: psa_key_derivation_setup::Operation) -> Self {
+        NativeOperation::PsaKeyDerivationSetup(op)
+    }
+}
+
+impl From<psa_key_derivation_input_bytes::Operation> for NativeOperation {
+    fn from(op: psa_key_derivation_input_bytes::Operation) -> Self {
+        NativeOperation::PsaKeyDerivationInputBytes(op)
+    }
+}
+
+impl From<psa_key_derivation_input_key::Operation> for NativeOperation {
+    fn from(op: psa_key_derivation_input_key::Operation) -> Self {
+        NativeOperation::PsaKeyDerivationInputKey(op)
+    }
+}
+
+impl From<psa_key_derivation_output_bytes::Operation> for NativeOperation {
+    fn from(op: psa_key_derivation_output_bytes::Operation) -> Self {
+        NativeOperation::PsaKeyDerivationOutputBytes(op)
+    }
+}
+
+impl From<psa_key_derivation_output_key::Operation> for NativeOperation {
+    fn from(op: psa_key_derivation_output_key::Operation) -> Self {
+        NativeOperation::PsaKeyDerivationOutputKey(op)
+    }
+}
+
+impl From<psa_hash_setup::Operation> for NativeOperation {
+    fn from(op: psa_hash_setup::Operation) -> Self {
+        NativeOperation::PsaHashSetup(op)
+    }
+}
+
+impl From<psa_hash_update::Operation> for NativeOperation {
+    fn from(op: psa_hash_update::Operation) -> Self {
+        NativeOperation::PsaHashUpdate(op)
+    }
+}
+
+impl From<psa_hash_finish::Operation> for NativeOperation {
+    fn from(op: psa_hash_finish::Operation) -> Self {
+        NativeOperation::PsaHashFinish(op)
+    }
+}
+
+impl From<psa_hash_abort::Operation> for NativeOperation {
+    fn from(op: psa_hash_abort::Operation) -> Self {
+        NativeOperation::PsaHashAbort(op)
+    }
+}
+
+impl From<psa_cipher_encrypt_setup::Operation> for NativeOperation {
+    fn from(op: psa_cipher_encrypt_setup::Operation) -> Self {
+        NativeOperation::PsaCipherEncryptSetup(op)
+    }
+}
+
+impl From<psa_cipher_decrypt_setup::Operation> for NativeOperation {
+    fn from(op: psa_cipher_decrypt_setup::Operation) -> Self {
+        NativeOperation::PsaCipherDecryptSetup(op)
+    }
+}
+
+impl From<psa_cipher_update::Operation> for NativeOperation {
+    fn from(op: psa_cipher_update::Operation) -> Self {
+        NativeOperation::PsaCipherUpdate(op)
+    }
+}
+
+impl From<psa_cipher_finish::Operation> for NativeOperation {
+    fn from(op: psa_cipher_finish::Operation) -> Self {
+        NativeOperation::PsaCipherFinish(op)
+    }
+}
+
+impl From<psa_cipher_abort::Operation> for NativeOperation {
+    fn from(op: psa_cipher_abort::Operation) -> Self {
+        NativeOperation::PsaCipherAbort(op)
+    }
+}
+
+impl From<psa_aead_encrypt_setup::Operation> for NativeOperation {
+    fn from(op: psa_aead_encrypt_setup::Operation) -> Self {
+        NativeOperation::PsaAeadEncryptSetup(op)
+    }
+}
+
+impl From<psa_aead_decrypt_setup::Operation> for NativeOperation {
+    fn from(op: psa_aead_decrypt_setup::Operation) -> Self {
+        NativeOperation::PsaAeadDecryptSetup(op)
+    }
+}
+
+impl From<psa_aead_update::Operation> for NativeOperation {
+    fn from(op: psa_aead_update::Operation) -> Self {
+        NativeOperation::PsaAeadUpdate(op)
+    }
+}
+
+impl From<psa_aead_finish::Operation> for NativeOperation {
+    fn from(op: psa_aead_finish::Operation) -> Self {
+        NativeOperation::PsaAeadFinish(op)
+    }
+}
+
+impl From<psa_aead_verify::Operation> for NativeOperation {
+    fn from(op: psa_aead_verify::Operation) -> Self {
+        NativeOperation::PsaAeadVerify(op)
+    }
+}
+
+impl From<psa_aead_abort::Operation> for NativeOperation {
+    fn from(op: psa_aead_abort::Operation) -> Self {
+        NativeOperation::PsaAeadAbort(op)
+    }
+}
+
+impl From<can_do_crypto::Operation> for NativeOperation {
+    fn from(op: can_do_crypto::Operation) -> Self {
+        NativeOperation::CanDoCrypto(op)
+    }
+}
+
+impl From<prepare_key_attestation::Operation> for NativeOperation {
+    fn from(op: prepare_key_attestation::Operation) -> Self {
+        NativeOperation::PrepareKeyAttestation(op)
+    }
+}
+
+impl From<attest_key::Operation> for NativeOperation {
+    fn from(op: attest_key::Operation) -> Self {
+        NativeOperation::AttestKey(op)
+    }
+}
+
+impl From<create_certificate_signing_request::Operation> for NativeOperation {
+    fn from(op: create_certificate_signing_request::Operation) -> Self {
+        NativeOperation::CreateCertificateSigningRequest(op)
+    }
+}
+
 impl From<list_providers::Result> for NativeResult {
     fn from(op: list_providers::Result) -> Self {
         NativeResult::ListProviders(op)
@@ -244,3 +864,273 @@ impl From<psa_verify_hash::Result> for NativeResult {
         NativeResult::PsaVerifyHash(op)
     }
 }
+
+impl From<psa_hash_compute::Result> for NativeResult {
+    fn from(op: psa_hash_compute::Result) -> Self {
+        NativeResult::PsaHashCompute(op)
+    }
+}
+
+impl From<psa_hash_compare::Result> for NativeResult {
+    fn from(op: psa_hash_compare::Result) -> Self {
+        NativeResult::PsaHashCompare(op)
+    }
+}
+
+impl From<psa_mac_compute::Result> for NativeResult {
+    fn from(op: psa_mac_compute::Result) -> Self {
+        NativeResult::PsaMacCompute(op)
+    }
+}
+
+impl From<psa_mac_verify::Result> for NativeResult {
+    fn from(op: psa_mac_verify::Result) -> Self {
+        NativeResult::PsaMacVerify(op)
+    }
+}
+
+impl From<psa_cipher_encrypt::Result> for NativeResult {
+    fn from(op: psa_cipher_encrypt::Result) -> Self {
+        NativeResult::PsaCipherEncrypt(op)
+    }
+}
+
+impl From<psa_cipher_decrypt::Result> for NativeResult {
+    fn from(op: psa_cipher_decrypt::Result) -> Self {
+        NativeResult::PsaCipherDecrypt(op)
+    }
+}
+
+impl From<psa_aead_encrypt::Result> for NativeResult {
+    fn from(op: psa_aead_encrypt::Result) -> Self {
+        NativeResult::PsaAeadEncrypt(op)
+    }
+}
+
+impl From<psa_aead_decrypt::Result> for NativeResult {
+    fn from(op: psa_aead_decrypt::Result) -> Self {
+        NativeResult::PsaAeadDecrypt(op)
+    }
+}
+
+impl From<psa_asymmetric_encrypt::Result> for NativeResult {
+    fn from(op: psa_asymmetric_encrypt::Result) -> Self {
+        NativeResult::PsaAsymmetricEncrypt(op)
+    }
+}
+
+impl From<psa_asymmetric_decrypt::Result> for NativeResult {
+    fn from(op: psa_asymmetric_decrypt::Result) -> Self {
+        NativeResult::PsaAsymmetricDecrypt(op)
+    }
+}
+
+impl From<psa_raw_key_agreement::Result> for NativeResult {
+    fn from(op: psa_raw_key_agreement::Result) -> Self {
+        NativeResult::PsaRawKeyAgreement(op)
+    }
+}
+
+impl From<psa_generate_random::Result> for NativeResult {
+    fn from(op: psa_generate_random::Result) -> Self {
+        NativeResult::PsaGenerateRandom(op)
+    }
+}
+
+impl From<psa_export_key::Result> for NativeResult {
+    fn from(op: psa_export_key::Result) -> Self {
+        NativeResult::PsaExportKey(op)
+    }
+}
+
+impl From<psa_get_key_attributes::Result> for NativeResult {
+    fn from(op: psa_get_key_attributes::Result) -> Self {
+        NativeResult::PsaGetKeyAttributes(op)
+    }
+}
+
+impl From<list_keys::Result> for NativeResult {
+    fn from(op: list_keys::Result) -> Self {
+        NativeResult::ListKeys(op)
+    }
+}
+
+impl From<list_authenticators::Result> for NativeResult {
+    fn from(op: list_authenticators::Result) -> Self {
+        NativeResult::ListAuthenticators(op)
+    }
+}
+
+impl From<list_clients::Result> for NativeResult {
+    fn from(op: list_clients::Result) -> Self {
+        NativeResult::ListClients(op)
+    }
+}
+
+impl From<delete_client::Result> for NativeResult {
+    fn from(op: delete_client::Result) -> Self {
+        NativeResult::DeleteClient(op)
+    }
+}
+
+impl From<psa_copy_key::Result> for NativeResult {
+    fn from(op: psa_copy_key::Result) -> Self {
+        NativeResult::PsaCopyKey(op)
+    }
+}
+
+impl From<psa_sign_message::Result> for NativeResult {
+    fn from(op: psa_sign_message::Result) -> Self {
+        NativeResult::PsaSignMessage(op)
+    }
+}
+
+impl From<psa_verify_message::Result> for NativeResult {
+    fn from(op: psa_verify_message::Result) -> Self {
+        NativeResult::PsaVerifyMessage(op)
+    }
+}
+
+impl From<psa_key_derivation_setup::Result> for NativeResult {
+    fn from(op: psa_key_derivation_setup::Result) -> Self {
+        NativeResult::PsaKeyDerivationSetup(op)
+    }
+}
+
+impl From<psa_key_derivation_input_bytes::Result> for NativeResult {
+    fn from(op: psa_key_derivation_input_bytes::Result) -> Self {
+        NativeResult::PsaKeyDerivationInputBytes(op)
+    }
+}
+
+impl From<psa_key_derivation_input_key::Result> for NativeResult {
+    fn from(op: psa_key_derivation_input_key::Result) -> Self {
+        NativeResult::PsaKeyDerivationInputKey(op)
+    }
+}
+
+impl From<psa_key_derivation_output_bytes::Result> for NativeResult {
+    fn from(op: psa_key_derivation_output_bytes::Result) -> Self {
+        NativeResult::PsaKeyDerivationOutputBytes(op)
+    }
+}
+
+impl From<psa_key_derivation_output_key::Result> for NativeResult {
+    fn from(op: psa_key_derivation_output_key::Result) -> Self {
+        NativeResult::PsaKeyDerivationOutputKey(op)
+    }
+}
+
+impl From<psa_hash_setup::Result> for NativeResult {
+    fn from(op: psa_hash_setup::Result) -> Self {
+        NativeResult::PsaHashSetup(op)
+    }
+}
+
+impl From<psa_hash_update::Result> for NativeResult {
+    fn from(op: psa_hash_update::Result) -> Self {
+        NativeResult::PsaHashUpdate(op)
+    }
+}
+
+impl From<psa_hash_finish::Result> for NativeResult {
+    fn from(op: psa_hash_finish::Result) -> Self {
+        NativeResult::PsaHashFinish(op)
+    }
+}
+
+impl From<psa_hash_abort::Result> for NativeResult {
+    fn from(op: psa_hash_abort::Result) -> Self {
+        NativeResult::PsaHashAbort(op)
+    }
+}
+
+impl From<psa_cipher_encrypt_setup::Result> for NativeResult {
+    fn from(op: psa_cipher_encrypt_setup::Result) -> Self {
+        NativeResult::PsaCipherEncryptSetup(op)
+    }
+}
+
+impl From<psa_cipher_decrypt_setup::Result> for NativeResult {
+    fn from(op: psa_cipher_decrypt_setup::Result) -> Self {
+        NativeResult::PsaCipherDecryptSetup(op)
+    }
+}
+
+impl From<psa_cipher_update::Result> for NativeResult {
+    fn from(op: psa_cipher_update::Result) -> Self {
+        NativeResult::PsaCipherUpdate(op)
+    }
+}
+
+impl From<psa_cipher_finish::Result> for NativeResult {
+    fn from(op: psa_cipher_finish::Result) -> Self {
+        NativeResult::PsaCipherFinish(op)
+    }
+}
+
+impl From<psa_cipher_abort::Result> for NativeResult {
+    fn from(op: psa_cipher_abort::Result) -> Self {
+        NativeResult::PsaCipherAbort(op)
+    }
+}
+
+impl From<psa_aead_encrypt_setup::Result> for NativeResult {
+    fn from(op: psa_aead_encrypt_setup::Result) -> Self {
+        NativeResult::PsaAeadEncryptSetup(op)
+    }
+}
+
+impl From<psa_aead_decrypt_setup::Result> for NativeResult {
+    fn from(op: psa_aead_decrypt_setup::Result) -> Self {
+        NativeResult::PsaAeadDecryptSetup(op)
+    }
+}
+
+impl From<psa_aead_update::Result> for NativeResult {
+    fn from(op: psa_aead_update::Result) -> Self {
+        NativeResult::PsaAeadUpdate(op)
+    }
+}
+
+impl From<psa_aead_finish::Result> for NativeResult {
+    fn from(op: psa_aead_finish::Result) -> Self {
+        NativeResult::PsaAeadFinish(op)
+    }
+}
+
+impl From<psa_aead_verify::Result> for NativeResult {
+    fn from(op: psa_aead_verify::Result) -> Self {
+        NativeResult::PsaAeadVerify(op)
+    }
+}
+
+impl From<psa_aead_abort::Result> for NativeResult {
+    fn from(op: psa_aead_abort::Result) -> Self {
+        NativeResult::PsaAeadAbort(op)
+    }
+}
+
+impl From<can_do_crypto::Result> for NativeResult {
+    fn from(op: can_do_crypto::Result) -> Self {
+        NativeResult::CanDoCrypto(op)
+    }
+}
+
+impl From<prepare_key_attestation::Result> for NativeResult {
+    fn from(op: prepare_key_attestation::Result) -> Self {
+        NativeResult::PrepareKeyAttestation(op)
+    }
+}
+
+impl From<attest_key::Result> for NativeResult {
+    fn from(op: attest_key::Result) -> Self {
+        NativeResult::AttestKey(op)
+    }
+}
+
+impl From<create_certificate_signing_request::Result> for NativeResult {
+    fn from(op: create_certificate_signing_request::Result) -> Self {
+        NativeResult::CreateCertificateSigningRequest(op)
+    }
+}
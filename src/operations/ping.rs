@@ -5,6 +5,8 @@
 //! The Ping operation is used to check if the service is alive and determine the highest wire
 //! protocol version a client can use.
 
+use crate::requests::WireProtocolVersion;
+
 /// Native object for Ping operation.
 #[derive(Copy, Clone, Debug)]
 pub struct Operation;
@@ -20,3 +22,20 @@ pub struct Result {
     /// Supported latest wire protocol version minor
     pub wire_protocol_version_min: u8,
 }
+
+impl Result {
+    /// Wire protocol version a client should use to talk to the service.
+    ///
+    /// This is the highest version supported by both this crate and the service, as chosen by
+    /// `WireProtocolVersion::negotiate`.
+    ///
+    /// # Errors
+    /// - if no version supported by this crate is compatible with the one of the service,
+    /// `WireProtocolVersionNotSupported` is returned.
+    pub fn wire_protocol_version(&self) -> crate::requests::Result<WireProtocolVersion> {
+        WireProtocolVersion::negotiate(
+            self.wire_protocol_version_maj,
+            self.wire_protocol_version_min,
+        )
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PrepareKeyAttestation operation
+//!
+//! Get the data needed before attesting a key with the `AttestKey` operation, such as the public
+//! part of the attesting key.
+
+use super::key_attestation::{validate_attested_key, AttestationMechanism};
+use super::psa_key_attributes::Attributes;
+
+/// Native object for the key attestation preparation operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// Name of the key to attest.
+    pub attested_key_name: String,
+    /// Mechanism to use for the attestation.
+    pub mechanism: AttestationMechanism,
+    /// Name of the key to attest the key with. If not given, the provider uses its default
+    /// attesting key for the mechanism.
+    pub attesting_key_name: Option<String>,
+}
+
+/// Native object for the key attestation preparation result.
+#[derive(Debug)]
+pub struct Result {
+    /// Data specific to the mechanism, needed to build the `AttestKey` operation or to verify its
+    /// token.
+    pub preparation_data: Vec<u8>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the attested key
+    ///
+    /// This method checks that the attested key is a key pair.
+    pub fn validate(&self, attested_key_attributes: Attributes) -> crate::requests::Result<()> {
+        validate_attested_key(attested_key_attributes)
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadDecrypt operation
+//!
+//! Process an authenticated decryption operation.
+
+use super::psa_aead_encrypt::{is_nonce_len_permitted, tag_length};
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{Aead, Algorithm};
+use crate::requests::ResponseStatus;
+
+/// Native object for AEAD decrypt operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the decryption operation.
+    pub key_name: String,
+    /// The AEAD algorithm to compute, compatible with the type of key.
+    pub alg: Aead,
+    /// Nonce or IV to use.
+    pub nonce: zeroize::Zeroizing<Vec<u8>>,
+    /// Additional data that has been authenticated but not encrypted.
+    pub additional_data: zeroize::Zeroizing<Vec<u8>>,
+    /// Data that has been authenticated and encrypted. For algorithms where the encrypted data
+    /// and the authentication tag are defined as separate inputs, the buffer must contain the
+    /// encrypted data followed by the authentication tag.
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for AEAD decrypt result.
+///
+/// If the ciphertext is not authentic, the operation fails with a `PsaErrorInvalidSignature`
+/// status code in the response.
+#[derive(Debug)]
+pub struct Result {
+    /// The `plaintext` field contains the decrypted data.
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows decrypting messages
+    /// * the key policy allows the decryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the length of the authentication tag is supported by the algorithm
+    /// * the length of the nonce is supported by the algorithm
+    /// * the ciphertext is long enough to contain the authentication tag, failing with
+    /// `PsaErrorInvalidSignature` otherwise
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_decrypt_message()?;
+        key_attributes.permits_alg(Algorithm::Aead(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::Aead(self.alg))?;
+        let tag_length = tag_length(self.alg)?;
+        if !is_nonce_len_permitted(self.alg, self.nonce.len()) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if self.ciphertext.len() < tag_length {
+            return Err(ResponseStatus::PsaErrorInvalidSignature);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::AeadWithDefaultLengthTag;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: true,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Aead(Aead::AeadWithShortenedTag {
+                    aead_alg: AeadWithDefaultLengthTag::Gcm,
+                    tag_length: 12,
+                }),
+            },
+        }
+    }
+
+    fn get_op(nonce_len: usize, ciphertext_len: usize) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: Aead::AeadWithShortenedTag {
+                aead_alg: AeadWithDefaultLengthTag::Gcm,
+                tag_length: 12,
+            },
+            nonce: vec![0xa5; nonce_len].into(),
+            additional_data: vec![0x11, 0x22, 0x33].into(),
+            ciphertext: vec![0xff; ciphertext_len].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(12, 44).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_decrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.decrypt = false;
+        assert_eq!(
+            get_op(12, 44).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_nonce_length() {
+        assert_eq!(
+            get_op(0, 44).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn ciphertext_shorter_than_tag() {
+        assert_eq!(
+            get_op(12, 8).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidSignature
+        );
+    }
+}
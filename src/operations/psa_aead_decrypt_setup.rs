@@ -0,0 +1,116 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadDecryptSetup operation
+//!
+//! Set up a multi-part authenticated decryption. The ciphertext is then given in chunks with
+//! `PsaAeadUpdate` and the decryption completed with `PsaAeadVerify`, all sent with the session
+//! in the `session` field of the request header. The plaintext returned by `PsaAeadUpdate` must
+//! not be trusted until `PsaAeadVerify` succeeds.
+
+use super::psa_aead_encrypt_setup::validate_setup;
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::Aead;
+use crate::requests::SessionHandle;
+
+/// Native object for AEAD decryption setup operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the decryption operation.
+    pub key_name: String,
+    /// The AEAD algorithm to compute, compatible with the type of key.
+    pub alg: Aead,
+    /// Nonce or IV to use.
+    pub nonce: zeroize::Zeroizing<Vec<u8>>,
+    /// Additional data that has been authenticated but not encrypted.
+    pub additional_data: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for AEAD decryption setup result.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// `session` identifies the decryption. It must be put in the `session` field of the header
+    /// of the requests performing its next steps.
+    pub session: SessionHandle,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows decrypting messages
+    /// * the key policy allows the decryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the algorithm can be used in multiple parts, failing with `PsaErrorNotSupported`
+    /// otherwise
+    /// * the length of the authentication tag is supported by the algorithm
+    /// * the length of the nonce is supported by the algorithm
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_decrypt_message()?;
+        validate_setup(self.alg, self.nonce.len(), key_attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{AeadWithDefaultLengthTag, Algorithm};
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+    use crate::requests::ResponseStatus;
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Chacha20,
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: true,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Aead(Aead::AeadWithDefaultLengthTag(
+                    AeadWithDefaultLengthTag::Chacha20Poly1305,
+                )),
+            },
+        }
+    }
+
+    fn get_op(nonce_len: usize) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Chacha20Poly1305),
+            nonce: vec![0xa5; nonce_len].into(),
+            additional_data: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(12).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_decrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.decrypt = false;
+        assert_eq!(
+            get_op(12).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_nonce_length() {
+        assert_eq!(
+            get_op(8).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
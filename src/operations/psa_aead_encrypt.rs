@@ -0,0 +1,242 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadEncrypt operation
+//!
+//! Process an authenticated encryption operation.
+
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{Aead, AeadWithDefaultLengthTag, Algorithm};
+use crate::requests::ResponseStatus;
+
+/// Native object for AEAD encrypt operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the encryption operation.
+    pub key_name: String,
+    /// The AEAD algorithm to compute, compatible with the type of key.
+    pub alg: Aead,
+    /// Nonce or IV to use.
+    pub nonce: zeroize::Zeroizing<Vec<u8>>,
+    /// Additional data that will be authenticated but not encrypted.
+    pub additional_data: zeroize::Zeroizing<Vec<u8>>,
+    /// Data that will be authenticated and encrypted.
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for AEAD encrypt result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `ciphertext` field contains the encrypted and authenticated data. For algorithms where
+    /// the encrypted data and the authentication tag are defined as separate outputs, the
+    /// authentication tag is appended to the encrypted data.
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows encrypting messages
+    /// * the key policy allows the encryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the length of the authentication tag is supported by the algorithm
+    /// * the length of the nonce is supported by the algorithm
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_encrypt_message()?;
+        key_attributes.permits_alg(Algorithm::Aead(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::Aead(self.alg))?;
+        if !is_tag_len_permitted(self.alg) || !is_nonce_len_permitted(self.alg, self.nonce.len()) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Length in bytes of the authentication tag produced by `alg`.
+///
+/// `PsaErrorInvalidArgument` is returned if the shortened tag length is not supported by the
+/// algorithm.
+pub(super) fn tag_length(alg: Aead) -> crate::requests::Result<usize> {
+    if !is_tag_len_permitted(alg) {
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+
+    match alg {
+        Aead::AeadWithDefaultLengthTag(_) => Ok(16),
+        Aead::AeadWithShortenedTag { tag_length, .. } => Ok(tag_length),
+    }
+}
+
+/// Check if the length of the authentication tag requested by `alg` is supported by the
+/// algorithm.
+pub(super) fn is_tag_len_permitted(alg: Aead) -> bool {
+    let (aead_alg, tag_length) = match alg {
+        Aead::AeadWithDefaultLengthTag(_) => return true,
+        Aead::AeadWithShortenedTag {
+            aead_alg,
+            tag_length,
+        } => (aead_alg, tag_length),
+    };
+    match aead_alg {
+        AeadWithDefaultLengthTag::Ccm => (4..=16).contains(&tag_length) && tag_length % 2 == 0,
+        AeadWithDefaultLengthTag::Gcm => {
+            tag_length == 4 || tag_length == 8 || (12..=16).contains(&tag_length)
+        }
+        AeadWithDefaultLengthTag::Chacha20Poly1305 => tag_length == 16,
+    }
+}
+
+/// Check if a nonce of `nonce_len` bytes can be used with `alg`.
+pub(super) fn is_nonce_len_permitted(alg: Aead, nonce_len: usize) -> bool {
+    let aead_alg = match alg {
+        Aead::AeadWithDefaultLengthTag(aead_alg) => aead_alg,
+        Aead::AeadWithShortenedTag { aead_alg, .. } => aead_alg,
+    };
+    match aead_alg {
+        AeadWithDefaultLengthTag::Ccm => (7..=13).contains(&nonce_len),
+        AeadWithDefaultLengthTag::Gcm => nonce_len > 0,
+        AeadWithDefaultLengthTag::Chacha20Poly1305 => nonce_len == 12,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: true,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Aead(Aead::AeadWithDefaultLengthTag(
+                    AeadWithDefaultLengthTag::Ccm,
+                )),
+            },
+        }
+    }
+
+    fn get_op(alg: Aead, nonce_len: usize) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg,
+            nonce: vec![0xa5; nonce_len].into(),
+            additional_data: vec![0x11, 0x22, 0x33].into(),
+            plaintext: vec![0xff; 32].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(
+            Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Ccm),
+            13,
+        )
+        .validate(get_attrs())
+        .unwrap();
+    }
+
+    #[test]
+    fn cannot_encrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.encrypt = false;
+        assert_eq!(
+            get_op(
+                Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Ccm),
+                13
+            )
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            get_op(
+                Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Gcm),
+                12
+            )
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_nonce_length() {
+        assert_eq!(
+            get_op(
+                Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Ccm),
+                16
+            )
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_tag_length() {
+        let alg = Aead::AeadWithShortenedTag {
+            aead_alg: AeadWithDefaultLengthTag::Ccm,
+            tag_length: 7,
+        };
+        let mut attrs = get_attrs();
+        attrs.policy.permitted_algorithms = Algorithm::Aead(alg);
+        assert_eq!(
+            get_op(alg, 13).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn tag_lengths() {
+        assert_eq!(
+            tag_length(Aead::AeadWithDefaultLengthTag(
+                AeadWithDefaultLengthTag::Gcm
+            ))
+            .unwrap(),
+            16
+        );
+        assert_eq!(
+            tag_length(Aead::AeadWithShortenedTag {
+                aead_alg: AeadWithDefaultLengthTag::Ccm,
+                tag_length: 8,
+            })
+            .unwrap(),
+            8
+        );
+        assert_eq!(
+            tag_length(Aead::AeadWithShortenedTag {
+                aead_alg: AeadWithDefaultLengthTag::Ccm,
+                tag_length: 7,
+            })
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        assert_eq!(
+            tag_length(Aead::AeadWithShortenedTag {
+                aead_alg: AeadWithDefaultLengthTag::Chacha20Poly1305,
+                tag_length: 12,
+            })
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
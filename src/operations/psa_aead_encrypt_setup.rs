@@ -0,0 +1,159 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadEncryptSetup operation
+//!
+//! Set up a multi-part authenticated encryption. The message is then given in chunks with
+//! `PsaAeadUpdate` and the encryption completed with `PsaAeadFinish`, all sent with the session
+//! in the `session` field of the request header. Each chunk must fit in the maximum body length
+//! and chunk length accepted by the service, but the total length of the message is not limited.
+
+use super::psa_aead_encrypt::{is_nonce_len_permitted, is_tag_len_permitted};
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{Aead, AeadWithDefaultLengthTag, Algorithm};
+use crate::requests::{ResponseStatus, SessionHandle};
+
+/// Native object for AEAD encryption setup operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the encryption operation.
+    pub key_name: String,
+    /// The AEAD algorithm to compute, compatible with the type of key.
+    pub alg: Aead,
+    /// Nonce or IV to use.
+    pub nonce: zeroize::Zeroizing<Vec<u8>>,
+    /// Additional data that will be authenticated but not encrypted.
+    pub additional_data: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for AEAD encryption setup result.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// `session` identifies the encryption. It must be put in the `session` field of the header
+    /// of the requests performing its next steps.
+    pub session: SessionHandle,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows encrypting messages
+    /// * the key policy allows the encryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the algorithm can be used in multiple parts, failing with `PsaErrorNotSupported`
+    /// otherwise
+    /// * the length of the authentication tag is supported by the algorithm
+    /// * the length of the nonce is supported by the algorithm
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_encrypt_message()?;
+        validate_setup(self.alg, self.nonce.len(), key_attributes)
+    }
+}
+
+/// Checks common to the setup of multi-part AEAD operations, once the usage flag is checked.
+///
+/// CCM needs the length of the whole message before processing it, which is not known when
+/// the message is given in chunks, so it is not supported.
+pub(super) fn validate_setup(
+    alg: Aead,
+    nonce_len: usize,
+    key_attributes: Attributes,
+) -> crate::requests::Result<()> {
+    key_attributes.permits_alg(Algorithm::Aead(alg))?;
+    key_attributes.compatible_with_alg(Algorithm::Aead(alg))?;
+    let aead_alg = match alg {
+        Aead::AeadWithDefaultLengthTag(aead_alg) => aead_alg,
+        Aead::AeadWithShortenedTag { aead_alg, .. } => aead_alg,
+    };
+    if matches!(aead_alg, AeadWithDefaultLengthTag::Ccm) {
+        return Err(ResponseStatus::PsaErrorNotSupported);
+    }
+    if !is_tag_len_permitted(alg) || !is_nonce_len_permitted(alg, nonce_len) {
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: true,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Aead(Aead::AeadWithDefaultLengthTag(
+                    AeadWithDefaultLengthTag::Gcm,
+                )),
+            },
+        }
+    }
+
+    fn get_op(aead_alg: AeadWithDefaultLengthTag, nonce_len: usize) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: Aead::AeadWithDefaultLengthTag(aead_alg),
+            nonce: vec![0xa5; nonce_len].into(),
+            additional_data: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(AeadWithDefaultLengthTag::Gcm, 12)
+            .validate(get_attrs())
+            .unwrap();
+    }
+
+    #[test]
+    fn cannot_encrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.encrypt = false;
+        assert_eq!(
+            get_op(AeadWithDefaultLengthTag::Gcm, 12)
+                .validate(attrs)
+                .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_nonce_length() {
+        assert_eq!(
+            get_op(AeadWithDefaultLengthTag::Gcm, 0)
+                .validate(get_attrs())
+                .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn ccm_not_supported() {
+        let mut attrs = get_attrs();
+        attrs.policy.permitted_algorithms = Algorithm::Aead(Aead::AeadWithDefaultLengthTag(
+            AeadWithDefaultLengthTag::Ccm,
+        ));
+        assert_eq!(
+            get_op(AeadWithDefaultLengthTag::Ccm, 13)
+                .validate(attrs)
+                .unwrap_err(),
+            ResponseStatus::PsaErrorNotSupported
+        );
+    }
+}
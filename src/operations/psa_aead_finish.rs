@@ -0,0 +1,19 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadFinish operation
+//!
+//! Finish a multi-part authenticated encryption and get its authentication tag. The session is
+//! closed by this operation.
+
+/// Native object for AEAD finish operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation;
+
+/// Native object for AEAD finish result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `ciphertext` field contains the remaining encrypted data.
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+    /// The `tag` field contains the authentication tag of the message.
+    pub tag: zeroize::Zeroizing<Vec<u8>>,
+}
@@ -0,0 +1,73 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAeadVerify operation
+//!
+//! Finish a multi-part authenticated decryption and check its authentication tag. The session is
+//! closed by this operation.
+
+use super::psa_aead_encrypt::tag_length;
+use crate::operations::psa_algorithm::Aead;
+use crate::requests::ResponseStatus;
+
+/// Native object for AEAD verify operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// The authentication tag of the message.
+    pub tag: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for AEAD verify result.
+///
+/// If the message is not authentic, the operation fails with a `PsaErrorInvalidSignature`
+/// status code in the response.
+#[derive(Debug)]
+pub struct Result {
+    /// The `plaintext` field contains the remaining decrypted data.
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the algorithm of the session
+    ///
+    /// This method checks that the length of the tag is the one produced by `alg`, failing with
+    /// `PsaErrorInvalidSignature` otherwise.
+    pub fn validate(&self, alg: Aead) -> crate::requests::Result<()> {
+        if self.tag.len() != tag_length(alg)? {
+            return Err(ResponseStatus::PsaErrorInvalidSignature);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::AeadWithDefaultLengthTag;
+
+    #[test]
+    fn validate_success() {
+        (Operation {
+            tag: vec![0xff; 16].into(),
+        })
+        .validate(Aead::AeadWithDefaultLengthTag(
+            AeadWithDefaultLengthTag::Gcm,
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn invalid_tag_length() {
+        assert_eq!(
+            (Operation {
+                tag: vec![0xff; 16].into(),
+            })
+            .validate(Aead::AeadWithShortenedTag {
+                aead_alg: AeadWithDefaultLengthTag::Gcm,
+                tag_length: 12,
+            })
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidSignature
+        );
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAsymmetricDecrypt operation
+//!
+//! Decrypt a short message with a private key.
+
+use super::psa_asymmetric_encrypt::modulus_length;
+use super::psa_key_attributes::{Attributes, Type};
+use crate::operations::psa_algorithm::{Algorithm, AsymmetricEncryption};
+use crate::requests::ResponseStatus;
+use crate::secrecy::Secret;
+use derivative::Derivative;
+
+/// Native object for asymmetric decryption operations.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the decryption operation.
+    pub key_name: String,
+    /// An asymmetric encryption algorithm that is compatible with the key type.
+    pub alg: AsymmetricEncryption,
+    /// The short encrypted message to be decrypted.
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+    /// An optional salt or label that was used when the message was encrypted. It is only
+    /// supported by algorithms that define one, such as the label of RSA OAEP.
+    pub salt: Option<zeroize::Zeroizing<Vec<u8>>>,
+}
+
+/// Native object for asymmetric decrypt result.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// The `plaintext` field contains the decrypted short message.
+    // Debug is not derived for this because it could expose secrets if printed or logged
+    // somewhere
+    #[derivative(Debug = "ignore")]
+    pub plaintext: Secret<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows decrypting messages
+    /// * the key policy allows the decryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the key is an RSA key pair, as decryption needs the private key
+    /// * if the algorithm does not support salt, no salt is provided
+    /// * the ciphertext has the length of the key modulus
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_decrypt_message()?;
+        key_attributes.permits_alg(Algorithm::AsymmetricEncryption(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::AsymmetricEncryption(self.alg))?;
+        if key_attributes.key_type != Type::RsaKeyPair {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if self.alg == AsymmetricEncryption::RsaPkcs1v15Crypt && self.salt.is_some() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if self.ciphertext.len() != modulus_length(key_attributes.bits) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::RsaKeyPair,
+            bits: 1024,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: true,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricEncryption(
+                    AsymmetricEncryption::RsaPkcs1v15Crypt,
+                ),
+            },
+        }
+    }
+
+    fn get_op(ciphertext_len: usize, salt: Option<Vec<u8>>) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
+            ciphertext: vec![0xff; ciphertext_len].into(),
+            salt: salt.map(zeroize::Zeroizing::new),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(128, None).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_decrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.decrypt = false;
+        assert_eq!(
+            get_op(128, None).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn public_key() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::RsaPublicKey;
+        assert_eq!(
+            get_op(128, None).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn salt_not_supported() {
+        assert_eq!(
+            get_op(128, Some(vec![0xa5; 8]))
+                .validate(get_attrs())
+                .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_ciphertext_length() {
+        assert_eq!(
+            get_op(256, None).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
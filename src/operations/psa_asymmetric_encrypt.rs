@@ -0,0 +1,167 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaAsymmetricEncrypt operation
+//!
+//! Encrypt a short message with a public key.
+
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{Algorithm, AsymmetricEncryption};
+use crate::requests::ResponseStatus;
+
+/// Native object for asymmetric encryption operations.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the encryption operation.
+    pub key_name: String,
+    /// An asymmetric encryption algorithm that is compatible with the key type.
+    pub alg: AsymmetricEncryption,
+    /// The short message to be encrypted.
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+    /// An optional salt or label for the encryption algorithm. It is only supported by
+    /// algorithms that define one, such as the label of RSA OAEP.
+    pub salt: Option<zeroize::Zeroizing<Vec<u8>>>,
+}
+
+/// Native object for asymmetric encrypt result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `ciphertext` field contains the encrypted short message.
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows encrypting messages
+    /// * the key policy allows the encryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * if the algorithm does not support salt, no salt is provided
+    /// * the plaintext is not longer than what the key modulus allows for the algorithm
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_encrypt_message()?;
+        key_attributes.permits_alg(Algorithm::AsymmetricEncryption(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::AsymmetricEncryption(self.alg))?;
+        if self.alg == AsymmetricEncryption::RsaPkcs1v15Crypt && self.salt.is_some() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if self.plaintext.len() > max_plaintext_length(key_attributes.bits, self.alg) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Size in bytes of the modulus of an RSA key of `bits` bits. It is also the size of the
+/// ciphertexts produced with that key.
+pub(super) fn modulus_length(bits: usize) -> usize {
+    bits.div_ceil(8)
+}
+
+/// Maximum size in bytes of a message that can be encrypted by `alg` with a key of `bits` bits.
+fn max_plaintext_length(bits: usize, alg: AsymmetricEncryption) -> usize {
+    let overhead = match alg {
+        AsymmetricEncryption::RsaPkcs1v15Crypt => 11,
+        AsymmetricEncryption::RsaOaep { hash_alg } => 2 * hash_alg.hash_length() + 2,
+    };
+    modulus_length(bits).saturating_sub(overhead)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::Hash;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::RsaPublicKey,
+            bits: 1024,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: true,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricEncryption(
+                    AsymmetricEncryption::RsaOaep {
+                        hash_alg: Hash::Sha256,
+                    },
+                ),
+            },
+        }
+    }
+
+    fn get_op(plaintext_len: usize, salt: Option<Vec<u8>>) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: AsymmetricEncryption::RsaOaep {
+                hash_alg: Hash::Sha256,
+            },
+            plaintext: vec![0xff; plaintext_len].into(),
+            salt: salt.map(zeroize::Zeroizing::new),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(62, Some(vec![0xa5; 8]))
+            .validate(get_attrs())
+            .unwrap();
+    }
+
+    #[test]
+    fn cannot_encrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.encrypt = false;
+        assert_eq!(
+            get_op(32, None).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let mut attrs = get_attrs();
+        attrs.policy.permitted_algorithms =
+            Algorithm::AsymmetricEncryption(AsymmetricEncryption::RsaPkcs1v15Crypt);
+        assert_eq!(
+            get_op(32, None).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn salt_not_supported() {
+        let mut attrs = get_attrs();
+        attrs.policy.permitted_algorithms =
+            Algorithm::AsymmetricEncryption(AsymmetricEncryption::RsaPkcs1v15Crypt);
+        let op = Operation {
+            key_name: String::from("some key"),
+            alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
+            plaintext: vec![0xff; 32].into(),
+            salt: Some(vec![0xa5; 8].into()),
+        };
+        assert_eq!(
+            op.validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn plaintext_too_long() {
+        assert_eq!(
+            get_op(63, None).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
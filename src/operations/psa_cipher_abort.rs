@@ -0,0 +1,15 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCipherAbort operation
+//!
+//! Abort a multi-part cipher operation and close its session.
+
+/// Native object for cipher abort operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation;
+
+/// Native object for cipher abort result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
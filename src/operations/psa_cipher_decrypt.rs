@@ -0,0 +1,150 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCipherDecrypt operation
+//!
+//! Decrypt a short message with a symmetric cipher.
+
+use super::psa_cipher_encrypt::{block_size, iv_length};
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{Algorithm, Cipher};
+use crate::requests::ResponseStatus;
+
+/// Native object for cipher decryption operations.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the decryption operation.
+    pub key_name: String,
+    /// The cipher algorithm to compute, compatible with the type of key.
+    pub alg: Cipher,
+    /// The initialisation vector (IV) that was generated when the message was encrypted. It must
+    /// be empty for algorithms that do not use an IV.
+    pub iv: zeroize::Zeroizing<Vec<u8>>,
+    /// The short encrypted message to be decrypted.
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for cipher decryption results.
+#[derive(Debug)]
+pub struct Result {
+    /// The `plaintext` field contains the decrypted short message.
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows decrypting messages
+    /// * the key policy allows the decryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the length of the IV is the one expected by the algorithm for this key type
+    /// * the length of the ciphertext is a multiple of the block size for block cipher modes
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_decrypt_message()?;
+        key_attributes.permits_alg(Algorithm::Cipher(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::Cipher(self.alg))?;
+        if self.iv.len() != iv_length(key_attributes.key_type, self.alg)? {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        match self.alg {
+            Cipher::EcbNoPadding | Cipher::CbcNoPadding => {
+                let block_size = block_size(key_attributes.key_type)?;
+                if self.ciphertext.len() % block_size != 0 {
+                    return Err(ResponseStatus::PsaErrorInvalidArgument);
+                }
+            }
+            Cipher::CbcPkcs7 => {
+                let block_size = block_size(key_attributes.key_type)?;
+                if self.ciphertext.is_empty() || self.ciphertext.len() % block_size != 0 {
+                    return Err(ResponseStatus::PsaErrorInvalidArgument);
+                }
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: true,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Cipher(Cipher::CbcPkcs7),
+            },
+        }
+    }
+
+    fn get_op(iv_len: usize, ciphertext_len: usize) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: Cipher::CbcPkcs7,
+            iv: vec![0xa5; iv_len].into(),
+            ciphertext: vec![0xff; ciphertext_len].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(16, 32).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_decrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.decrypt = false;
+        assert_eq!(
+            get_op(16, 32).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let mut attrs = get_attrs();
+        attrs.policy.permitted_algorithms = Algorithm::Cipher(Cipher::Ctr);
+        assert_eq!(
+            get_op(16, 32).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_iv_length() {
+        assert_eq!(
+            get_op(12, 32).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_ciphertext_length() {
+        assert_eq!(
+            get_op(16, 20).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        assert_eq!(
+            get_op(16, 0).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
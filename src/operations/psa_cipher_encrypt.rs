@@ -0,0 +1,170 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCipherEncrypt operation
+//!
+//! Encrypt a short message with a symmetric cipher.
+
+use super::psa_key_attributes::{Attributes, Type};
+use crate::operations::psa_algorithm::{Algorithm, Cipher};
+use crate::requests::ResponseStatus;
+
+/// Native object for cipher encryption operations.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the encryption operation.
+    pub key_name: String,
+    /// The cipher algorithm to compute, compatible with the type of key.
+    pub alg: Cipher,
+    /// The short message to be encrypted.
+    pub plaintext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for cipher encryption results.
+#[derive(Debug)]
+pub struct Result {
+    /// The initialisation vector (IV) randomly generated by the service for this encryption. It is
+    /// empty for algorithms that do not use an IV.
+    pub iv: zeroize::Zeroizing<Vec<u8>>,
+    /// The `ciphertext` field contains the encrypted short message.
+    pub ciphertext: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows encrypting messages
+    /// * the key policy allows the encryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the length of the plaintext is a multiple of the block size for algorithms without
+    /// padding
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_encrypt_message()?;
+        key_attributes.permits_alg(Algorithm::Cipher(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::Cipher(self.alg))?;
+        if matches!(self.alg, Cipher::EcbNoPadding | Cipher::CbcNoPadding)
+            && self.plaintext.len() % block_size(key_attributes.key_type)? != 0
+        {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Size in bytes of the block of the block cipher used with keys of type `key_type`.
+///
+/// `PsaErrorInvalidArgument` is returned for key types that are not used with block ciphers.
+pub(super) fn block_size(key_type: Type) -> crate::requests::Result<usize> {
+    match key_type {
+        Type::Aes | Type::Camellia => Ok(16),
+        Type::Des => Ok(8),
+        _ => Err(ResponseStatus::PsaErrorInvalidArgument),
+    }
+}
+
+/// Size in bytes of the IV used by the `alg` cipher with keys of type `key_type`.
+///
+/// `PsaErrorInvalidArgument` is returned if the algorithm can not be used with the key type.
+pub(super) fn iv_length(key_type: Type, alg: Cipher) -> crate::requests::Result<usize> {
+    match alg {
+        Cipher::EcbNoPadding => Ok(0),
+        Cipher::StreamCipher => match key_type {
+            Type::Chacha20 => Ok(12),
+            Type::Arc4 => Ok(0),
+            _ => Err(ResponseStatus::PsaErrorInvalidArgument),
+        },
+        Cipher::Ctr
+        | Cipher::Cfb
+        | Cipher::Ofb
+        | Cipher::Xts
+        | Cipher::CbcNoPadding
+        | Cipher::CbcPkcs7 => block_size(key_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: true,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Cipher(Cipher::CbcNoPadding),
+            },
+        }
+    }
+
+    fn get_op(alg: Cipher, plaintext_len: usize) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg,
+            plaintext: vec![0xff; plaintext_len].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(Cipher::CbcNoPadding, 32)
+            .validate(get_attrs())
+            .unwrap();
+    }
+
+    #[test]
+    fn cannot_encrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.encrypt = false;
+        assert_eq!(
+            get_op(Cipher::CbcNoPadding, 32)
+                .validate(attrs)
+                .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            get_op(Cipher::Ctr, 32).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn invalid_plaintext_length() {
+        assert_eq!(
+            get_op(Cipher::CbcNoPadding, 17)
+                .validate(get_attrs())
+                .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn iv_lengths() {
+        assert_eq!(iv_length(Type::Aes, Cipher::CbcPkcs7).unwrap(), 16);
+        assert_eq!(iv_length(Type::Des, Cipher::Ctr).unwrap(), 8);
+        assert_eq!(iv_length(Type::Aes, Cipher::EcbNoPadding).unwrap(), 0);
+        assert_eq!(iv_length(Type::Chacha20, Cipher::StreamCipher).unwrap(), 12);
+        assert_eq!(
+            iv_length(Type::Aes, Cipher::StreamCipher).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
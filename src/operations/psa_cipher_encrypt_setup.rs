@@ -0,0 +1,114 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCipherEncryptSetup operation
+//!
+//! Set up a multi-part encryption with a symmetric cipher. The message is then given in chunks
+//! with `PsaCipherUpdate` and the encryption completed with `PsaCipherFinish`, all sent with the
+//! session in the `session` field of the request header. Each chunk must fit in the maximum body
+//! length and chunk length accepted by the service, but the total length of the message is not
+//! limited.
+
+use super::psa_cipher_encrypt::iv_length;
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{Algorithm, Cipher};
+use crate::requests::SessionHandle;
+
+/// Native object for cipher encryption setup operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the encryption operation.
+    pub key_name: String,
+    /// The cipher algorithm to compute, compatible with the type of key.
+    pub alg: Cipher,
+}
+
+/// Native object for cipher encryption setup result.
+#[derive(Debug)]
+pub struct Result {
+    /// `session` identifies the encryption. It must be put in the `session` field of the header
+    /// of the requests performing its next steps.
+    pub session: SessionHandle,
+    /// The initialisation vector (IV) randomly generated by the service for this encryption. It is
+    /// empty for algorithms that do not use an IV.
+    pub iv: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows encrypting messages
+    /// * the key policy allows the encryption algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    /// * the algorithm can be used with the key type to generate an IV
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_encrypt_message()?;
+        key_attributes.permits_alg(Algorithm::Cipher(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::Cipher(self.alg))?;
+        // Only checks that the algorithm can be used with the key type. The length of the IV the
+        // service generates is not needed here.
+        iv_length(key_attributes.key_type, self.alg).map(|_| ())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+    use crate::requests::ResponseStatus;
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: true,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Cipher(Cipher::Ctr),
+            },
+        }
+    }
+
+    fn get_op(alg: Cipher) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg,
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(Cipher::Ctr).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn cannot_encrypt() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.encrypt = false;
+        assert_eq!(
+            get_op(Cipher::Ctr).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            get_op(Cipher::Ofb).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+}
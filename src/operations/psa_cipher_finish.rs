@@ -0,0 +1,16 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCipherFinish operation
+//!
+//! Finish a multi-part cipher operation. The session is closed by this operation.
+
+/// Native object for cipher finish operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation;
+
+/// Native object for cipher finish result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `output` field contains the remaining data of the encryption or decryption.
+    pub output: zeroize::Zeroizing<Vec<u8>>,
+}
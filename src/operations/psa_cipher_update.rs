@@ -0,0 +1,62 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCipherUpdate operation
+//!
+//! Encrypt or decrypt a message fragment in a multi-part cipher operation.
+
+use crate::requests::ResponseStatus;
+
+/// Native object for cipher update operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// The message fragment to be encrypted or decrypted.
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for cipher update result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `output` field contains the data produced from this fragment. Block ciphers might
+    /// keep part of the input until more data is given, so its length can differ from the length
+    /// of the input.
+    pub output: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the maximum length of the fragments the
+    /// service accepts to process in one operation
+    ///
+    /// This method checks that:
+    /// * the length of the message fragment is not larger than `max_chunk`
+    pub fn validate(&self, max_chunk: usize) -> crate::requests::Result<()> {
+        if self.input.len() > max_chunk {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_op(input_len: usize) -> Operation {
+        Operation {
+            input: vec![0xa5; input_len].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(32).validate(32).unwrap();
+    }
+
+    #[test]
+    fn chunk_over_limit() {
+        assert_eq!(
+            get_op(33).validate(32).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaCopyKey operation
+//!
+//! Copy a key into a new key, possibly with a more restrictive policy.
+
+use super::psa_key_attributes::{Attributes, Policy, UsageFlags};
+use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, SignHash};
+use crate::requests::ResponseStatus;
+
+/// Native object for key copying operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// `key_name` identifies the key to copy. Its policy must allow copying it.
+    pub key_name: String,
+    /// `new_key_name` specifies a name by which the service will identify the new key. Key
+    /// name must be unique per application.
+    pub new_key_name: String,
+    /// `attributes` specifies the attributes for the new key. The type and size must be the
+    /// ones of the source key, or left as zero for the size. The policy of the new key is the
+    /// intersection of this policy and the one of the source key.
+    pub attributes: Attributes,
+}
+
+/// Native object for the result of a key copying operation.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the source key and
+    /// compute the attributes of the new key
+    ///
+    /// This method checks that:
+    /// * the source key policy allows copying the key
+    /// * the key type of the new key is the one of the source key
+    /// * the size of the new key is the one of the source key, or zero
+    /// * the policies of the source and new keys allow an algorithm in common, if both of them
+    /// allow an algorithm
+    ///
+    /// The returned attributes have the lifetime of the new key, the type and size of the
+    /// source key, and the intersection of both policies.
+    pub fn validate(
+        &self,
+        source_key_attributes: Attributes,
+    ) -> crate::requests::Result<Attributes> {
+        if !source_key_attributes.policy.usage_flags.copy {
+            return Err(ResponseStatus::PsaErrorNotPermitted);
+        }
+        if self.attributes.key_type != source_key_attributes.key_type
+            || (self.attributes.bits != 0 && self.attributes.bits != source_key_attributes.bits)
+        {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(Attributes {
+            lifetime: self.attributes.lifetime,
+            key_type: source_key_attributes.key_type,
+            bits: source_key_attributes.bits,
+            policy: policy_intersection(source_key_attributes.policy, self.attributes.policy)?,
+        })
+    }
+}
+
+/// Compute the policy allowing only what is allowed by both `policy1` and `policy2`.
+///
+/// If either policy permits `Algorithm::None`, the resulting policy permits no algorithm.
+/// Otherwise, `PsaErrorInvalidArgument` is returned if the policies do not permit any algorithm
+/// in common.
+fn policy_intersection(policy1: Policy, policy2: Policy) -> crate::requests::Result<Policy> {
+    let flags1 = policy1.usage_flags;
+    let flags2 = policy2.usage_flags;
+
+    Ok(Policy {
+        usage_flags: UsageFlags {
+            export: flags1.export && flags2.export,
+            copy: flags1.copy && flags2.copy,
+            cache: flags1.cache && flags2.cache,
+            encrypt: flags1.encrypt && flags2.encrypt,
+            decrypt: flags1.decrypt && flags2.decrypt,
+            sign_message: flags1.sign_message && flags2.sign_message,
+            verify_message: flags1.verify_message && flags2.verify_message,
+            sign_hash: flags1.sign_hash && flags2.sign_hash,
+            verify_hash: flags1.verify_hash && flags2.verify_hash,
+            derive: flags1.derive && flags2.derive,
+        },
+        permitted_algorithms: algorithm_intersection(
+            policy1.permitted_algorithms,
+            policy2.permitted_algorithms,
+        )?,
+    })
+}
+
+/// Compute the algorithm permitted by both `alg1` and `alg2`, taking wildcards into account.
+///
+/// If either of them is `Algorithm::None`, no algorithm is permitted.
+fn algorithm_intersection(alg1: Algorithm, alg2: Algorithm) -> crate::requests::Result<Algorithm> {
+    if alg1 == alg2 {
+        Ok(alg1)
+    } else if alg1 == Algorithm::None || alg2 == Algorithm::None {
+        Ok(Algorithm::None)
+    } else if is_wildcard_of(alg1, alg2) {
+        Ok(alg2)
+    } else if is_wildcard_of(alg2, alg1) {
+        Ok(alg1)
+    } else {
+        Err(ResponseStatus::PsaErrorInvalidArgument)
+    }
+}
+
+/// Check if `wildcard` is a signature algorithm permitting any hash that includes `alg`.
+fn is_wildcard_of(wildcard: Algorithm, alg: Algorithm) -> bool {
+    let (wildcard, alg) = match (wildcard, alg) {
+        (Algorithm::AsymmetricSignature(wildcard), Algorithm::AsymmetricSignature(alg)) => {
+            (wildcard, alg)
+        }
+        _ => return false,
+    };
+    matches!(
+        (wildcard, alg),
+        (
+            AsymmetricSignature::RsaPkcs1v15Sign {
+                hash_alg: SignHash::Any,
+            },
+            AsymmetricSignature::RsaPkcs1v15Sign { .. }
+        ) | (
+            AsymmetricSignature::RsaPss {
+                hash_alg: SignHash::Any,
+            },
+            AsymmetricSignature::RsaPss { .. }
+        ) | (
+            AsymmetricSignature::Ecdsa {
+                hash_alg: SignHash::Any,
+            },
+            AsymmetricSignature::Ecdsa { .. }
+        ) | (
+            AsymmetricSignature::DeterministicEcdsa {
+                hash_alg: SignHash::Any,
+            },
+            AsymmetricSignature::DeterministicEcdsa { .. }
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Cipher, Hash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Type};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: true,
+                    copy: true,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: true,
+                    verify_message: true,
+                    sign_hash: true,
+                    verify_hash: true,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: SignHash::Any,
+                }),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        let mut attributes = get_attrs();
+        attributes.lifetime = Lifetime::Volatile;
+        attributes.bits = 0;
+        attributes.policy.usage_flags.export = false;
+        attributes.policy.usage_flags.copy = false;
+        attributes.policy.usage_flags.verify_message = false;
+        attributes.policy.usage_flags.verify_hash = false;
+        attributes.policy.permitted_algorithms =
+            Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                hash_alg: Hash::Sha256.into(),
+            });
+        Operation {
+            key_name: String::from("some key"),
+            new_key_name: String::from("sign-only copy"),
+            attributes,
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        let attrs = get_op().validate(get_attrs()).unwrap();
+
+        assert_eq!(attrs.lifetime, Lifetime::Volatile);
+        assert_eq!(attrs.key_type, get_attrs().key_type);
+        assert_eq!(attrs.bits, 256);
+        assert!(attrs.policy.usage_flags.sign_hash);
+        assert!(attrs.policy.usage_flags.sign_message);
+        assert!(!attrs.policy.usage_flags.verify_hash);
+        assert!(!attrs.policy.usage_flags.export);
+        assert!(!attrs.policy.usage_flags.copy);
+        assert_eq!(
+            attrs.policy.permitted_algorithms,
+            Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                hash_alg: Hash::Sha256.into(),
+            })
+        );
+    }
+
+    #[test]
+    fn cannot_copy() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.copy = false;
+        assert_eq!(
+            get_op().validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_key_type() {
+        let mut op = get_op();
+        op.attributes.key_type = Type::RsaKeyPair;
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn wrong_bits() {
+        let mut op = get_op();
+        op.attributes.bits = 384;
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn no_algorithm_in_common() {
+        let mut op = get_op();
+        op.attributes.policy.permitted_algorithms =
+            Algorithm::AsymmetricSignature(AsymmetricSignature::DeterministicEcdsa {
+                hash_alg: Hash::Sha256.into(),
+            });
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn no_algorithm_permitted() {
+        let mut op = get_op();
+        op.attributes.policy.permitted_algorithms = Algorithm::None;
+        let attrs = op.validate(get_attrs()).unwrap();
+
+        assert_eq!(attrs.policy.permitted_algorithms, Algorithm::None);
+        assert!(attrs.policy.usage_flags.sign_hash);
+    }
+
+    #[test]
+    fn algorithm_intersections() {
+        let alg = Algorithm::Cipher(Cipher::Ctr);
+        assert_eq!(algorithm_intersection(alg, alg).unwrap(), alg);
+        assert_eq!(
+            algorithm_intersection(alg, Algorithm::None).unwrap(),
+            Algorithm::None
+        );
+        assert_eq!(
+            algorithm_intersection(alg, Algorithm::Cipher(Cipher::Cfb)).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaExportKey operation
+//!
+//! Export a key in binary format. See the book for the format description.
+
+use super::psa_key_attributes::Attributes;
+use crate::secrecy::Secret;
+use derivative::Derivative;
+
+/// Native object for key exporting operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// `key_name` identifies the key that will be exported. Its policy must allow exporting it.
+    pub key_name: String,
+}
+
+/// Native object for result of key export operation.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub struct Result {
+    /// `data` holds the bytes defining the key, formatted as specified
+    /// by the provider for which the request was made.
+    // Debug is not derived for this because it could expose secrets if printed or logged
+    // somewhere
+    #[derivative(Debug = "ignore")]
+    pub data: Secret<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows exporting the key
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        key_attributes.can_export()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, Cipher};
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+    use crate::requests::ResponseStatus;
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Aes,
+            bits: 128,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: true,
+                    copy: false,
+                    cache: false,
+                    encrypt: true,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Cipher(Cipher::Ctr),
+            },
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        (Operation {
+            key_name: String::from("some key"),
+        })
+        .validate(get_attrs())
+        .unwrap();
+    }
+
+    #[test]
+    fn cannot_export() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.export = false;
+        assert_eq!(
+            (Operation {
+                key_name: String::from("some key"),
+            })
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn debug_hides_data() {
+        let result = Result {
+            data: Secret::new(vec![0xde, 0xad, 0xbe, 0xef]),
+        };
+        assert!(!format!("{:?}", result).contains("222"));
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaGenerateRandom operation
+//!
+//! Generate random bytes.
+
+use crate::requests::ResponseStatus;
+
+/// Maximum number of random bytes that can be requested in one operation.
+///
+/// Converters reject operations requesting more with `PsaErrorInsufficientMemory`, so that a
+/// client cannot make the service allocate unbounded memory. Services can set a lower limit with
+/// `Operation::validate`.
+pub const MAX_SIZE: usize = 4096;
+
+/// Native object for random bytes generation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// `size` is the number of random bytes to generate. It can not be larger than `MAX_SIZE`.
+    pub size: usize,
+}
+
+/// Native object for the result of random bytes generation.
+#[derive(Debug)]
+pub struct Result {
+    /// `random_bytes` contains the generated random bytes.
+    pub random_bytes: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the maximum number of random bytes the
+    /// service accepts to generate in one operation
+    ///
+    /// This method checks that:
+    /// * the number of random bytes requested is not larger than `max_size`
+    pub fn validate(&self, max_size: usize) -> crate::requests::Result<()> {
+        if self.size > max_size {
+            return Err(ResponseStatus::PsaErrorInsufficientMemory);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_success() {
+        (Operation { size: 32 }).validate(32).unwrap();
+    }
+
+    #[test]
+    fn size_over_limit() {
+        assert_eq!(
+            (Operation { size: 33 }).validate(32).unwrap_err(),
+            ResponseStatus::PsaErrorInsufficientMemory
+        );
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaGetKeyAttributes operation
+//!
+//! Retrieve the attributes of a key.
+
+use super::psa_key_attributes::Attributes;
+
+/// Native object for key attributes query operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// `key_name` identifies the key whose attributes are requested.
+    pub key_name: String,
+}
+
+/// Native object for the result of a key attributes query operation.
+///
+/// The attributes can be used to run the `validate` method of other operations before sending
+/// them to the service.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// `attributes` contains the type, size, lifetime and policy of the key.
+    pub attributes: Attributes,
+}
@@ -0,0 +1,15 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashAbort operation
+//!
+//! Abort a multi-part hash operation and close its session.
+
+/// Native object for hash abort operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation;
+
+/// Native object for hash abort result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
@@ -0,0 +1,70 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashCompare operation
+//!
+//! Calculate the hash (digest) of a message and compare it with a reference value.
+
+use crate::operations::psa_algorithm::Hash;
+use crate::requests::ResponseStatus;
+
+/// Native object for hash compare operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// The hash algorithm to compute.
+    pub alg: Hash,
+    /// Buffer containing message to hash.
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+    /// Buffer containing the expected hash value.
+    pub hash: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for hash compare result.
+///
+/// The true result of the operation is sent as a `status` code in the response. A hash that does
+/// not match the one calculated is reported with `PsaErrorInvalidSignature`.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+impl Operation {
+    /// Validate the contents of the operation
+    ///
+    /// This method checks that the length of the expected hash is the digest size of the
+    /// requested algorithm.
+    pub fn validate(&self) -> crate::requests::Result<()> {
+        if self.hash.len() != self.alg.hash_length() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_success() {
+        (Operation {
+            alg: Hash::Sha256,
+            input: vec![0x11, 0x22, 0x33].into(),
+            hash: vec![0xff; 32].into(),
+        })
+        .validate()
+        .unwrap();
+    }
+
+    #[test]
+    fn invalid_hash_length() {
+        assert_eq!(
+            (Operation {
+                alg: Hash::Sha384,
+                input: vec![0x11, 0x22, 0x33].into(),
+                hash: vec![0xff; 32].into(),
+            })
+            .validate()
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashCompute operation
+//!
+//! Calculate the hash (digest) of a message.
+
+use crate::operations::psa_algorithm::Hash;
+use crate::requests::ResponseStatus;
+
+/// Native object for hash compute operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// The hash algorithm to compute.
+    pub alg: Hash,
+    /// Buffer containing message to hash.
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for hash compute result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `hash` field contains the hash of the message.
+    pub hash: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Result {
+    /// Validate the contents of the result against the hash algorithm that was requested
+    ///
+    /// This method checks that the length of the hash is the digest size of `alg`.
+    pub fn validate(&self, alg: Hash) -> crate::requests::Result<()> {
+        if self.hash.len() != alg.hash_length() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_success() {
+        (Result {
+            hash: vec![0xff; 32].into(),
+        })
+        .validate(Hash::Sha256)
+        .unwrap();
+    }
+
+    #[test]
+    fn invalid_hash_length() {
+        assert_eq!(
+            (Result {
+                hash: vec![0xff; 32].into(),
+            })
+            .validate(Hash::Sha512)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashFinish operation
+//!
+//! Finish the calculation of the hash of a message. The session is closed by this operation.
+
+use crate::operations::psa_algorithm::Hash;
+use crate::requests::ResponseStatus;
+
+/// Native object for hash finish operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation;
+
+/// Native object for hash finish result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `hash` field contains the hash of the message.
+    pub hash: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Result {
+    /// Validate the contents of the result against the hash algorithm of the session
+    ///
+    /// This method checks that the length of the hash is the digest size of `alg`.
+    pub fn validate(&self, alg: Hash) -> crate::requests::Result<()> {
+        if self.hash.len() != alg.hash_length() {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_success() {
+        (Result {
+            hash: vec![0xff; 32].into(),
+        })
+        .validate(Hash::Sha256)
+        .unwrap();
+    }
+
+    #[test]
+    fn invalid_hash_length() {
+        assert_eq!(
+            (Result {
+                hash: vec![0xff; 32].into(),
+            })
+            .validate(Hash::Sha384)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashSetup operation
+//!
+//! Set up a multi-part hash operation. The message is then given in chunks with `PsaHashUpdate`
+//! and the hash read with `PsaHashFinish`, all sent with the session in the `session` field of
+//! the request header. This allows hashing messages larger than the maximum body length accepted
+//! by the service.
+
+use crate::operations::psa_algorithm::Hash;
+use crate::requests::SessionHandle;
+
+/// Native object for hash setup operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// The hash algorithm to compute.
+    pub alg: Hash,
+}
+
+/// Native object for hash setup result.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// `session` identifies the hash operation. It must be put in the `session` field of the
+    /// header of the requests performing its next steps.
+    pub session: SessionHandle,
+}
@@ -0,0 +1,18 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaHashUpdate operation
+//!
+//! Add a message fragment to a multi-part hash operation.
+
+/// Native object for hash update operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// Buffer containing the message fragment to hash.
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for hash update result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
@@ -5,6 +5,7 @@
 //! Import a key in binary format.
 
 use super::psa_key_attributes::Attributes;
+use crate::secrecy::Secret;
 use derivative::Derivative;
 
 /// Native object for cryptographic key importing operation.
@@ -22,7 +23,7 @@ pub struct Operation {
     // Debug is not derived for this because it could expose secrets if printed or logged
     // somewhere
     #[derivative(Debug = "ignore")]
-    pub data: crate::secrecy::Secret<Vec<u8>>,
+    pub data: Secret<Vec<u8>>,
 }
 
 /// Native object for the result of a cryptographic key import operation.
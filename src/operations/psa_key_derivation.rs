@@ -0,0 +1,168 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # Multi-part key derivation
+//!
+//! Types shared by the key derivation operations. A key derivation is started with
+//! `PsaKeyDerivationSetup`, which returns a session. The inputs are then given with
+//! `PsaKeyDerivationInputBytes` and `PsaKeyDerivationInputKey` and the outputs read with
+//! `PsaKeyDerivationOutputBytes` and `PsaKeyDerivationOutputKey`, all of them sent with the
+//! session in the `session` field of the request header.
+
+use crate::operations::psa_algorithm::KeyDerivation;
+use crate::requests::{ResponseStatus, Result};
+
+/// Role of an input of a key derivation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DerivationStep {
+    /// A secret input, such as a key or a shared secret.
+    Secret,
+    /// A label for the key derivation.
+    Label,
+    /// A salt for the key derivation.
+    Salt,
+    /// An information string for the key derivation.
+    Info,
+    /// A seed for the key derivation.
+    Seed,
+}
+
+/// State of a key derivation session, checking that its steps are performed in the order
+/// required by the algorithm.
+///
+/// Services should keep one `State` per session and update it before executing each step. For
+/// HKDF, the salt is optional and must come before the secret, and the information string is
+/// optional. For the TLS 1.2 PRF and PSK-to-MS algorithms, the seed, secret and label must all
+/// be given in this order. Each input can only be given once and no input can be given once
+/// the output has started.
+#[derive(Clone, Debug)]
+pub struct State {
+    alg: KeyDerivation,
+    inputs: Vec<DerivationStep>,
+    output_started: bool,
+}
+
+impl State {
+    /// Create the state of a key derivation session just set up with `alg`.
+    pub fn new(alg: KeyDerivation) -> Self {
+        State {
+            alg,
+            inputs: Vec::new(),
+            output_started: false,
+        }
+    }
+
+    /// Algorithm of the key derivation.
+    pub fn alg(&self) -> KeyDerivation {
+        self.alg
+    }
+
+    /// Check that an input for `step` can be given now and record it.
+    ///
+    /// `PsaErrorInvalidArgument` is returned if the algorithm does not use this step and
+    /// `PsaErrorBadState` if the step is given in the wrong order.
+    pub fn input(&mut self, step: DerivationStep) -> Result<()> {
+        if self.output_started || self.inputs.contains(&step) {
+            return Err(ResponseStatus::PsaErrorBadState);
+        }
+        match self.alg {
+            KeyDerivation::Hkdf { .. } => match step {
+                DerivationStep::Salt if self.inputs.contains(&DerivationStep::Secret) => {
+                    return Err(ResponseStatus::PsaErrorBadState)
+                }
+                DerivationStep::Salt | DerivationStep::Secret | DerivationStep::Info => (),
+                _ => return Err(ResponseStatus::PsaErrorInvalidArgument),
+            },
+            KeyDerivation::Tls12Prf { .. } | KeyDerivation::Tls12PskToMs { .. } => {
+                let position = TLS12_STEPS
+                    .iter()
+                    .position(|tls12_step| *tls12_step == step)
+                    .ok_or(ResponseStatus::PsaErrorInvalidArgument)?;
+                if self.inputs.len() != position {
+                    return Err(ResponseStatus::PsaErrorBadState);
+                }
+            }
+        }
+        self.inputs.push(step);
+
+        Ok(())
+    }
+
+    /// Check that output can be read now and record it.
+    ///
+    /// `PsaErrorBadState` is returned if some inputs required by the algorithm are missing.
+    pub fn output(&mut self) -> Result<()> {
+        let required: &[DerivationStep] = match self.alg {
+            KeyDerivation::Hkdf { .. } => &[DerivationStep::Secret],
+            KeyDerivation::Tls12Prf { .. } | KeyDerivation::Tls12PskToMs { .. } => &TLS12_STEPS,
+        };
+        if !required.iter().all(|step| self.inputs.contains(step)) {
+            return Err(ResponseStatus::PsaErrorBadState);
+        }
+        self.output_started = true;
+
+        Ok(())
+    }
+}
+
+/// Inputs of the TLS 1.2 key derivations, in the order they must be given.
+const TLS12_STEPS: [DerivationStep; 3] = [
+    DerivationStep::Seed,
+    DerivationStep::Secret,
+    DerivationStep::Label,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::Hash;
+
+    #[test]
+    fn hkdf_steps() {
+        let mut state = State::new(KeyDerivation::Hkdf {
+            hash_alg: Hash::Sha256,
+        });
+        assert_eq!(state.output(), Err(ResponseStatus::PsaErrorBadState));
+        state.input(DerivationStep::Salt).unwrap();
+        state.input(DerivationStep::Secret).unwrap();
+        assert_eq!(
+            state.input(DerivationStep::Salt),
+            Err(ResponseStatus::PsaErrorBadState)
+        );
+        assert_eq!(
+            state.input(DerivationStep::Seed),
+            Err(ResponseStatus::PsaErrorInvalidArgument)
+        );
+        state.input(DerivationStep::Info).unwrap();
+        state.output().unwrap();
+        state.output().unwrap();
+    }
+
+    #[test]
+    fn no_input_after_output() {
+        let mut state = State::new(KeyDerivation::Hkdf {
+            hash_alg: Hash::Sha256,
+        });
+        state.input(DerivationStep::Secret).unwrap();
+        state.output().unwrap();
+        assert_eq!(
+            state.input(DerivationStep::Info),
+            Err(ResponseStatus::PsaErrorBadState)
+        );
+    }
+
+    #[test]
+    fn tls12_prf_steps() {
+        let mut state = State::new(KeyDerivation::Tls12Prf {
+            hash_alg: Hash::Sha256,
+        });
+        assert_eq!(
+            state.input(DerivationStep::Secret),
+            Err(ResponseStatus::PsaErrorBadState)
+        );
+        state.input(DerivationStep::Seed).unwrap();
+        state.input(DerivationStep::Secret).unwrap();
+        assert_eq!(state.output(), Err(ResponseStatus::PsaErrorBadState));
+        state.input(DerivationStep::Label).unwrap();
+        state.output().unwrap();
+    }
+}
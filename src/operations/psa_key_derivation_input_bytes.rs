@@ -0,0 +1,22 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaKeyDerivationInputBytes operation
+//!
+//! Provide an input for a key derivation as a buffer.
+
+use super::psa_key_derivation::DerivationStep;
+
+/// Native object for key derivation bytes input operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// `step` specifies which input the data is used for.
+    pub step: DerivationStep,
+    /// `data` contains the input data.
+    pub data: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for key derivation bytes input result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
@@ -0,0 +1,150 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaKeyDerivationInputKey operation
+//!
+//! Provide an input for a key derivation in the form of a key.
+
+use super::psa_key_attributes::{Attributes, Type};
+use super::psa_key_derivation::DerivationStep;
+use crate::operations::psa_algorithm::{Algorithm, KeyDerivation};
+use crate::requests::ResponseStatus;
+
+/// Native object for key derivation key input operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// `step` specifies which input the key is used for.
+    pub step: DerivationStep,
+    /// `key_name` identifies the key to use as input.
+    pub key_name: String,
+}
+
+/// Native object for key derivation key input result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    /// and the algorithm of the key derivation
+    ///
+    /// This method checks that:
+    /// * the key policy allows derivation
+    /// * the key policy allows the algorithm of the key derivation
+    /// * the key is of the `Derive` type
+    pub fn validate(
+        &self,
+        alg: KeyDerivation,
+        key_attributes: Attributes,
+    ) -> crate::requests::Result<()> {
+        if !key_attributes.policy.usage_flags.derive {
+            return Err(ResponseStatus::PsaErrorNotPermitted);
+        }
+        key_attributes.permits_alg(Algorithm::KeyDerivation(alg))?;
+        if key_attributes.key_type != Type::Derive {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::Hash;
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Derive,
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: true,
+                },
+                permitted_algorithms: Algorithm::KeyDerivation(KeyDerivation::Hkdf {
+                    hash_alg: Hash::Sha256,
+                }),
+            },
+        }
+    }
+
+    fn get_op() -> Operation {
+        Operation {
+            step: DerivationStep::Secret,
+            key_name: String::from("some key"),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op()
+            .validate(
+                KeyDerivation::Hkdf {
+                    hash_alg: Hash::Sha256,
+                },
+                get_attrs(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn cannot_derive() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.derive = false;
+        assert_eq!(
+            get_op()
+                .validate(
+                    KeyDerivation::Hkdf {
+                        hash_alg: Hash::Sha256,
+                    },
+                    attrs,
+                )
+                .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            get_op()
+                .validate(
+                    KeyDerivation::Tls12Prf {
+                        hash_alg: Hash::Sha256,
+                    },
+                    get_attrs(),
+                )
+                .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_key_type() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::Aes;
+        assert_eq!(
+            get_op()
+                .validate(
+                    KeyDerivation::Hkdf {
+                        hash_alg: Hash::Sha256,
+                    },
+                    attrs,
+                )
+                .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaKeyDerivationOutputBytes operation
+//!
+//! Read some data from a key derivation.
+
+use crate::requests::ResponseStatus;
+
+/// Native object for key derivation bytes output operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// `size` is the number of bytes to read.
+    pub size: usize,
+}
+
+/// Native object for key derivation bytes output result.
+#[derive(Debug)]
+pub struct Result {
+    /// `data` contains the bytes read from the key derivation.
+    pub data: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the maximum number of bytes the service
+    /// accepts to output in one operation
+    ///
+    /// This method checks that:
+    /// * the number of bytes requested is not larger than `max_size`
+    pub fn validate(&self, max_size: usize) -> crate::requests::Result<()> {
+        if self.size > max_size {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_success() {
+        (Operation { size: 32 }).validate(32).unwrap();
+    }
+
+    #[test]
+    fn size_over_limit() {
+        assert_eq!(
+            (Operation { size: 33 }).validate(32).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,23 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaKeyDerivationOutputKey operation
+//!
+//! Derive a key from a key derivation.
+
+use super::psa_key_attributes::Attributes;
+
+/// Native object for key derivation key output operation.
+#[derive(Clone, Debug)]
+pub struct Operation {
+    /// `key_name` specifies a name by which the service will identify the new key. Key
+    /// name must be unique per application.
+    pub key_name: String,
+    /// `attributes` specifies the attributes for the new key.
+    pub attributes: Attributes,
+}
+
+/// Native object for key derivation key output result.
+///
+/// The true result is returned in the `status` field of the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
@@ -0,0 +1,23 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaKeyDerivationSetup operation
+//!
+//! Set up a multi-part key derivation.
+
+use crate::operations::psa_algorithm::KeyDerivation;
+use crate::requests::SessionHandle;
+
+/// Native object for key derivation setup operation.
+#[derive(Copy, Clone, Debug)]
+pub struct Operation {
+    /// `alg` is the key derivation algorithm to compute.
+    pub alg: KeyDerivation,
+}
+
+/// Native object for key derivation setup result.
+#[derive(Copy, Clone, Debug)]
+pub struct Result {
+    /// `session` identifies the key derivation. It must be put in the `session` field of the
+    /// header of the requests performing its next steps.
+    pub session: SessionHandle,
+}
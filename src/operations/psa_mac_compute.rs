@@ -0,0 +1,115 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaMacCompute operation
+//!
+//! Calculate the MAC (message authentication code) of a message.
+
+use super::can_sign_message;
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{Algorithm, Mac};
+
+/// Native object for MAC compute operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the MAC operation.
+    pub key_name: String,
+    /// The MAC algorithm to compute, compatible with the type of key.
+    pub alg: Mac,
+    /// Buffer containing the input message.
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for MAC compute result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `mac` field contains the MAC of the message.
+    pub mac: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows signing messages, or hashes which implies it
+    /// * the key policy allows the MAC algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        can_sign_message(key_attributes)?;
+        key_attributes.permits_alg(Algorithm::Mac(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::Mac(self.alg))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{FullLengthMac, Hash};
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+    use crate::requests::ResponseStatus;
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Hmac,
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: true,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Mac(Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                })),
+            },
+        }
+    }
+
+    fn get_op(hash_alg: Hash) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: Mac::FullLength(FullLengthMac::Hmac { hash_alg }),
+            input: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(Hash::Sha256).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn validate_success_sign_hash() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.sign_message = false;
+        attrs.policy.usage_flags.sign_hash = true;
+        get_op(Hash::Sha256).validate(attrs).unwrap();
+    }
+
+    #[test]
+    fn cannot_sign() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.sign_message = false;
+        assert_eq!(
+            get_op(Hash::Sha256).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            get_op(Hash::Sha384).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+}
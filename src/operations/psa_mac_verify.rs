@@ -0,0 +1,118 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaMacVerify operation
+//!
+//! Calculate the MAC of a message and compare it with a reference value.
+
+use super::can_verify_message;
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{Algorithm, Mac};
+
+/// Native object for MAC verify operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// `key_name` specifies the key to be used for verification.
+    pub key_name: String,
+    /// The MAC algorithm to compute, compatible with the type of key.
+    pub alg: Mac,
+    /// Buffer containing the input message.
+    pub input: zeroize::Zeroizing<Vec<u8>>,
+    /// Buffer containing the expected MAC value.
+    pub mac: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for MAC verify result.
+///
+/// The true result of the operation is sent as a `status` code in the response. A MAC that does
+/// not match the one calculated is reported with `PsaErrorInvalidSignature`.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows verifying messages, or hashes which implies it
+    /// * the key policy allows the MAC algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        can_verify_message(key_attributes)?;
+        key_attributes.permits_alg(Algorithm::Mac(self.alg))?;
+        key_attributes.compatible_with_alg(Algorithm::Mac(self.alg))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{FullLengthMac, Hash};
+    use crate::operations::psa_key_attributes::{Lifetime, Policy, Type, UsageFlags};
+    use crate::requests::ResponseStatus;
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::Hmac,
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: true,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::Mac(Mac::FullLength(FullLengthMac::Hmac {
+                    hash_alg: Hash::Sha256,
+                })),
+            },
+        }
+    }
+
+    fn get_op(hash_alg: Hash) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: Mac::FullLength(FullLengthMac::Hmac { hash_alg }),
+            input: vec![0x11, 0x22, 0x33].into(),
+            mac: vec![0xa5; 32].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(Hash::Sha256).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn validate_success_verify_hash() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.verify_message = false;
+        attrs.policy.usage_flags.verify_hash = true;
+        get_op(Hash::Sha256).validate(attrs).unwrap();
+    }
+
+    #[test]
+    fn cannot_verify() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.verify_message = false;
+        assert_eq!(
+            get_op(Hash::Sha256).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        assert_eq!(
+            get_op(Hash::Sha384).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaRawKeyAgreement operation
+//!
+//! Perform a key agreement and return the raw shared secret.
+
+use super::psa_key_attributes::{Attributes, EccFamily, Type};
+use crate::operations::psa_algorithm::{Algorithm, KeyAgreement, RawKeyAgreement};
+use crate::requests::ResponseStatus;
+
+/// Native object for raw key agreement operation.
+#[derive(Debug)]
+pub struct Operation {
+    /// `alg` specifies the raw key agreement algorithm to use. It must allow the `derive` usage
+    /// flag.
+    pub alg: RawKeyAgreement,
+    /// `private_key_name` specifies a name of the private key to use in the key agreement
+    /// operation.
+    pub private_key_name: String,
+    /// `peer_key` contains the bytes of a peer public key, to be used in the key agreement
+    /// operation. This must be in the format that `PsaImportKey` accepts.
+    pub peer_key: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for raw key agreement result.
+#[derive(Debug)]
+pub struct Result {
+    /// `shared_secret` contains the raw output of the key agreement. It should not be used
+    /// directly as key material, but only as input to a key derivation function.
+    pub shared_secret: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the private key it
+    /// targets
+    ///
+    /// This method checks that:
+    /// * the key policy allows derivation
+    /// * the key policy allows the key agreement algorithm requested in the operation
+    /// * the key is an ECC key pair for ECDH, or a DH key pair for FFDH
+    /// * the length of the peer key is consistent with the curve or group of the private key
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        if !key_attributes.policy.usage_flags.derive {
+            return Err(ResponseStatus::PsaErrorNotPermitted);
+        }
+        key_attributes.permits_alg(Algorithm::KeyAgreement(KeyAgreement::Raw(self.alg)))?;
+        if !is_key_type_compatible(self.alg, key_attributes.key_type) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        if !is_peer_key_len_permitted(key_attributes, self.peer_key.len()) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Check if a private key of type `key_type` can be used in a key agreement with `alg`: ECDH
+/// needs an ECC key pair and FFDH a DH key pair.
+///
+/// `compatible_with_alg` of psa-crypto can not be used for this, as it does not accept key pairs
+/// for key agreement algorithms.
+pub(super) fn is_key_type_compatible(alg: RawKeyAgreement, key_type: Type) -> bool {
+    matches!(
+        (alg, key_type),
+        (RawKeyAgreement::Ecdh, Type::EccKeyPair { .. })
+            | (RawKeyAgreement::Ffdh, Type::DhKeyPair { .. })
+    )
+}
+
+/// Check if a peer public key of `peer_key_len` bytes can be used in a key agreement with a
+/// private key having the `key_attributes` attributes.
+fn is_peer_key_len_permitted(key_attributes: Attributes, peer_key_len: usize) -> bool {
+    let key_bytes = key_attributes.bits.div_ceil(8);
+    match key_attributes.key_type {
+        // Montgomery curves public keys are encoded as a single coordinate.
+        Type::EccKeyPair {
+            curve_family: EccFamily::Montgomery,
+        } => peer_key_len == key_bytes,
+        // Other curves public keys are encoded as an uncompressed point: 0x04 || x || y.
+        Type::EccKeyPair { .. } => peer_key_len == 1 + 2 * key_bytes,
+        // Finite field public keys are encoded as a big-endian integer smaller than the prime.
+        Type::DhKeyPair { .. } => peer_key_len > 0 && peer_key_len <= key_bytes,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_key_attributes::{DhFamily, Lifetime, Policy, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: true,
+                },
+                permitted_algorithms: Algorithm::KeyAgreement(KeyAgreement::Raw(
+                    RawKeyAgreement::Ecdh,
+                )),
+            },
+        }
+    }
+
+    fn get_op(peer_key_len: usize) -> Operation {
+        Operation {
+            alg: RawKeyAgreement::Ecdh,
+            private_key_name: String::from("some key"),
+            peer_key: vec![0x04; peer_key_len].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(65).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn validate_montgomery_success() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::EccKeyPair {
+            curve_family: EccFamily::Montgomery,
+        };
+        attrs.bits = 255;
+        get_op(32).validate(attrs).unwrap();
+    }
+
+    #[test]
+    fn cannot_derive() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.derive = false;
+        assert_eq!(
+            get_op(65).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let op = Operation {
+            alg: RawKeyAgreement::Ffdh,
+            private_key_name: String::from("some key"),
+            peer_key: vec![0x04; 65].into(),
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_key_type() {
+        let mut attrs = get_attrs();
+        attrs.key_type = Type::DhKeyPair {
+            group_family: DhFamily::Rfc7919,
+        };
+        attrs.bits = 2048;
+        assert_eq!(
+            get_op(65).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+
+    #[test]
+    fn invalid_peer_key_length() {
+        assert_eq!(
+            get_op(33).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaSignMessage operation
+//!
+//! Sign a message with a private key. The message is hashed by the service.
+
+use super::can_sign_message;
+use super::psa_key_attributes::Attributes;
+use crate::operations::psa_algorithm::{AsymmetricSignature, SignHash};
+use crate::requests::ResponseStatus;
+
+/// Native object for asymmetric message sign operations.
+#[derive(Debug)]
+pub struct Operation {
+    /// Defines which key should be used for the signing operation.
+    pub key_name: String,
+    /// An asymmetric signature algorithm that is compatible with the type of key. The message is
+    /// hashed with the hash algorithm it specifies.
+    pub alg: AsymmetricSignature,
+    /// The message to sign.
+    pub message: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for asymmetric message sign result.
+#[derive(Debug)]
+pub struct Result {
+    /// The `signature` field contains the resulting bytes from the signing operation. The format of
+    /// the signature is as specified by the provider doing the signing.
+    pub signature: zeroize::Zeroizing<Vec<u8>>,
+}
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the signing algorithm specifies the hash algorithm to use on the message
+    /// * the key policy allows signing messages, or hashes which implies it
+    /// * the key policy allows the signing algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        validate_message_signing(self.alg, key_attributes)
+    }
+}
+
+/// Check that a key with the `key_attributes` attributes can sign messages with `alg`.
+///
+/// This is the validation of `PsaSignMessage`, shared with the operations signing data they
+/// build themselves.
+pub(super) fn validate_message_signing(
+    alg: AsymmetricSignature,
+    key_attributes: Attributes,
+) -> crate::requests::Result<()> {
+    if !is_message_alg(alg) {
+        return Err(ResponseStatus::PsaErrorInvalidArgument);
+    }
+    can_sign_message(key_attributes)?;
+    key_attributes.permits_alg(alg.into())?;
+    key_attributes.compatible_with_alg(alg.into())?;
+
+    Ok(())
+}
+
+/// Check if `alg` specifies the hash algorithm used to hash the message before signing it.
+///
+/// Algorithms signing raw data or permitting any hash can not be used to sign messages.
+pub(super) fn is_message_alg(alg: AsymmetricSignature) -> bool {
+    matches!(
+        alg,
+        AsymmetricSignature::RsaPkcs1v15Sign {
+            hash_alg: SignHash::Specific(_),
+        } | AsymmetricSignature::RsaPss {
+            hash_alg: SignHash::Specific(_),
+        } | AsymmetricSignature::Ecdsa {
+            hash_alg: SignHash::Specific(_),
+        } | AsymmetricSignature::DeterministicEcdsa {
+            hash_alg: SignHash::Specific(_),
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, Hash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: true,
+                    verify_message: false,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: SignHash::Any,
+                }),
+            },
+        }
+    }
+
+    fn get_op(hash_alg: SignHash) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg: AsymmetricSignature::Ecdsa { hash_alg },
+            message: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(Hash::Sha256.into()).validate(get_attrs()).unwrap();
+    }
+
+    #[test]
+    fn validate_success_sign_hash() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.sign_message = false;
+        attrs.policy.usage_flags.sign_hash = true;
+        get_op(Hash::Sha256.into()).validate(attrs).unwrap();
+    }
+
+    #[test]
+    fn cannot_sign() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.sign_message = false;
+        assert_eq!(
+            get_op(Hash::Sha256.into()).validate(attrs).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn wrong_scheme() {
+        let op = Operation {
+            key_name: String::from("some key"),
+            alg: AsymmetricSignature::RsaPss {
+                hash_alg: Hash::Sha256.into(),
+            },
+            message: vec![0x11, 0x22, 0x33].into(),
+        };
+        assert_eq!(
+            op.validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn no_specific_hash() {
+        assert_eq!(
+            get_op(SignHash::Any).validate(get_attrs()).unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+        assert!(!is_message_alg(AsymmetricSignature::EcdsaAny));
+        assert!(!is_message_alg(AsymmetricSignature::RsaPkcs1v15SignRaw));
+        assert!(!is_message_alg(AsymmetricSignature::RsaPss {
+            hash_alg: SignHash::Any,
+        }));
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! # PsaVerifyMessage operation
+//!
+//! Verify the signature of a message using a public key. The message is hashed by the service.
+use super::can_verify_message;
+use super::psa_key_attributes::Attributes;
+use super::psa_sign_message::is_message_alg;
+use crate::operations::psa_algorithm::AsymmetricSignature;
+use crate::requests::ResponseStatus;
+
+/// Native object for asymmetric verification of message signatures.
+#[derive(Debug)]
+pub struct Operation {
+    /// `key_name` specifies the key to be used for verification.
+    pub key_name: String,
+    /// An asymmetric signature algorithm that is compatible with the type of key. The message is
+    /// hashed with the hash algorithm it specifies.
+    pub alg: AsymmetricSignature,
+    /// The message whose signature is to be verified.
+    pub message: zeroize::Zeroizing<Vec<u8>>,
+    /// Buffer containing the signature to verify.
+    pub signature: zeroize::Zeroizing<Vec<u8>>,
+}
+
+/// Native object for asymmetric verification of message signatures.
+///
+/// The true result of the operation is sent as a `status` code in the response.
+#[derive(Copy, Clone, Debug)]
+pub struct Result;
+
+impl Operation {
+    /// Validate the contents of the operation against the attributes of the key it targets
+    ///
+    /// This method checks that:
+    /// * the verification algorithm specifies the hash algorithm to use on the message
+    /// * the key policy allows verifying signatures on messages, or on hashes which implies it
+    /// * the key policy allows the verification algorithm requested in the operation
+    /// * the key type is compatible with the requested algorithm
+    pub fn validate(&self, key_attributes: Attributes) -> crate::requests::Result<()> {
+        if !is_message_alg(self.alg) {
+            return Err(ResponseStatus::PsaErrorInvalidArgument);
+        }
+        can_verify_message(key_attributes)?;
+        key_attributes.permits_alg(self.alg.into())?;
+        key_attributes.compatible_with_alg(self.alg.into())?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::psa_algorithm::{Algorithm, Hash, SignHash};
+    use crate::operations::psa_key_attributes::{EccFamily, Lifetime, Policy, Type, UsageFlags};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: false,
+                    verify_message: true,
+                    sign_hash: false,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: SignHash::Any,
+                }),
+            },
+        }
+    }
+
+    fn get_op(alg: AsymmetricSignature) -> Operation {
+        Operation {
+            key_name: String::from("some key"),
+            alg,
+            message: vec![0x11, 0x22, 0x33].into(),
+            signature: vec![0xa5; 64].into(),
+        }
+    }
+
+    #[test]
+    fn validate_success() {
+        get_op(AsymmetricSignature::Ecdsa {
+            hash_alg: Hash::Sha256.into(),
+        })
+        .validate(get_attrs())
+        .unwrap();
+    }
+
+    #[test]
+    fn validate_success_verify_hash() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.verify_message = false;
+        attrs.policy.usage_flags.verify_hash = true;
+        get_op(AsymmetricSignature::Ecdsa {
+            hash_alg: Hash::Sha256.into(),
+        })
+        .validate(attrs)
+        .unwrap();
+    }
+
+    #[test]
+    fn cannot_verify() {
+        let mut attrs = get_attrs();
+        attrs.policy.usage_flags.verify_message = false;
+        assert_eq!(
+            get_op(AsymmetricSignature::Ecdsa {
+                hash_alg: Hash::Sha256.into(),
+            })
+            .validate(attrs)
+            .unwrap_err(),
+            ResponseStatus::PsaErrorNotPermitted
+        );
+    }
+
+    #[test]
+    fn no_specific_hash() {
+        assert_eq!(
+            get_op(AsymmetricSignature::Ecdsa {
+                hash_alg: SignHash::Any,
+            })
+            .validate(get_attrs())
+            .unwrap_err(),
+            ResponseStatus::PsaErrorInvalidArgument
+        );
+    }
+}
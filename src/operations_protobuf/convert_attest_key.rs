@@ -0,0 +1,161 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_key_attestation::attesting_key_name_from_proto;
+use super::generated_ops::attest_key::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::attest_key::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            attested_key_name: proto_op.attested_key_name,
+            mechanism: proto_op
+                .mechanism
+                .ok_or_else(|| {
+                    error!("mechanism field of attest_key::Operation message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            nonce: proto_op.nonce,
+            attesting_key_name: attesting_key_name_from_proto(proto_op.attesting_key_name),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            attested_key_name: op.attested_key_name,
+            mechanism: Some(op.mechanism.try_into()?),
+            nonce: op.nonce,
+            attesting_key_name: op.attesting_key_name.unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            token: proto_result.token,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            token: result.token,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::attest_key::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::attest_key::{Operation, Result};
+    use crate::operations::key_attestation::AttestationMechanism;
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            attested_key_name: "test name".to_string(),
+            mechanism: AttestationMechanism::VendorSpecific(0x1234),
+            nonce: vec![0x11, 0x22, 0x33],
+            attesting_key_name: None,
+        }
+    }
+
+    #[test]
+    fn attest_key_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert_eq!(proto.attested_key_name, "test name");
+        assert_eq!(proto.nonce, vec![0x11, 0x22, 0x33]);
+        assert!(proto.attesting_key_name.is_empty());
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.attested_key_name, "test name");
+        assert_eq!(op.mechanism, AttestationMechanism::VendorSpecific(0x1234));
+        assert_eq!(op.nonce, vec![0x11, 0x22, 0x33]);
+        assert!(op.attesting_key_name.is_none());
+    }
+
+    #[test]
+    fn attest_key_empty_mechanism() {
+        let mut proto: OperationProto = Default::default();
+        proto.attested_key_name = "test name".to_string();
+
+        let op: std::result::Result<Operation, _> = proto.try_into();
+
+        assert!(op.is_err());
+    }
+
+    #[test]
+    fn attest_key_resp_round_trip() {
+        let result = Result {
+            token: vec![0x11, 0x22, 0x33],
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        assert_eq!(proto.token, vec![0x11, 0x22, 0x33]);
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(result.token, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn op_attest_key_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::AttestKey(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER.body_to_operation(body, Opcode::AttestKey).is_ok());
+    }
+
+    #[test]
+    fn resp_attest_key_e2e() {
+        let result = Result {
+            token: vec![0x11, 0x22, 0x33],
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::AttestKey(result))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::AttestKey).is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::AttestKey)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::AttestKey)
+            .is_err());
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::can_do_crypto::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::can_do_crypto::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            attributes: proto_op.attributes.map(TryInto::try_into).transpose()?,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("alg field of can_do_crypto::Operation message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            attributes: op.attributes.map(TryInto::try_into).transpose()?,
+            alg: Some(op.alg.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let reason = if proto_result.reason == 0 {
+            None
+        } else {
+            Some(ResponseStatus::try_from(u16::try_from(
+                proto_result.reason,
+            )?)?)
+        };
+        Ok(Result { reason })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        let reason = match result.reason {
+            None => 0,
+            Some(ResponseStatus::Success) => {
+                error!("reason field of can_do_crypto::Result can not be Success.");
+                return Err(ResponseStatus::InvalidEncoding);
+            }
+            Some(reason) => reason as u32,
+        };
+        Ok(ResultProto { reason })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::can_do_crypto::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::can_do_crypto::{Operation, Result};
+    use crate::operations::psa_algorithm::{Algorithm, Cipher};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            attributes: Some(Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::Aes,
+                bits: 128,
+                policy: Policy {
+                    usage_flags: UsageFlags {
+                        export: false,
+                        copy: false,
+                        cache: false,
+                        encrypt: true,
+                        decrypt: false,
+                        sign_message: false,
+                        verify_message: false,
+                        sign_hash: false,
+                        verify_hash: false,
+                        derive: false,
+                    },
+                    permitted_algorithms: Algorithm::Cipher(Cipher::Ctr),
+                },
+            }),
+            alg: Algorithm::Cipher(Cipher::Ctr),
+        }
+    }
+
+    #[test]
+    fn can_do_crypto_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert!(proto.attributes.is_some());
+        assert!(proto.alg.is_some());
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.attributes, get_op().attributes);
+        assert_eq!(op.alg, get_op().alg);
+    }
+
+    #[test]
+    fn can_do_crypto_op_without_attributes() {
+        let op = Operation {
+            attributes: None,
+            ..get_op()
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        assert!(proto.attributes.is_none());
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert!(op.attributes.is_none());
+    }
+
+    #[test]
+    fn can_do_crypto_empty_alg() {
+        let proto: OperationProto = Default::default();
+        let op: std::result::Result<Operation, _> = proto.try_into();
+        assert!(op.is_err());
+    }
+
+    #[test]
+    fn can_do_crypto_resp_round_trip() {
+        let result = Result {
+            reason: Some(ResponseStatus::PsaErrorNotSupported),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        assert_eq!(proto.reason, ResponseStatus::PsaErrorNotSupported as u32);
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(
+            result,
+            Result {
+                reason: Some(ResponseStatus::PsaErrorNotSupported),
+            }
+        );
+    }
+
+    #[test]
+    fn can_do_crypto_supported_resp() {
+        let proto: ResultProto = Result { reason: None }
+            .try_into()
+            .expect("Failed to convert");
+        assert_eq!(proto.reason, 0);
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert!(result.supported());
+    }
+
+    #[test]
+    fn can_do_crypto_success_reason() {
+        let result = Result {
+            reason: Some(ResponseStatus::Success),
+        };
+
+        let proto: std::result::Result<ResultProto, _> = result.try_into();
+
+        assert!(proto.is_err());
+    }
+
+    #[test]
+    fn can_do_crypto_invalid_reason() {
+        let mut proto: ResultProto = Default::default();
+        proto.reason = 0xffff_ffff;
+
+        let result: std::result::Result<Result, _> = proto.try_into();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn op_can_do_crypto_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::CanDoCrypto(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::CanDoCrypto)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_can_do_crypto_e2e() {
+        let result = Result { reason: None };
+        let body = CONVERTER
+            .result_to_body(NativeResult::CanDoCrypto(result))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::CanDoCrypto).is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::CanDoCrypto)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::CanDoCrypto)
+            .is_err());
+    }
+}
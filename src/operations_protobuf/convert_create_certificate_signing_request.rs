@@ -0,0 +1,226 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::create_certificate_signing_request::{
+    Extension as ExtensionProto, NameAttribute as NameAttributeProto, Operation as OperationProto,
+    Result as ResultProto,
+};
+use crate::operations::create_certificate_signing_request::{
+    Extension, NameAttribute, Operation, Result,
+};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl From<NameAttributeProto> for NameAttribute {
+    fn from(proto_attribute: NameAttributeProto) -> Self {
+        NameAttribute {
+            oid: proto_attribute.oid,
+            value: proto_attribute.value,
+        }
+    }
+}
+
+impl From<NameAttribute> for NameAttributeProto {
+    fn from(attribute: NameAttribute) -> Self {
+        NameAttributeProto {
+            oid: attribute.oid,
+            value: attribute.value,
+        }
+    }
+}
+
+impl From<ExtensionProto> for Extension {
+    fn from(proto_extension: ExtensionProto) -> Self {
+        Extension {
+            oid: proto_extension.oid,
+            critical: proto_extension.critical,
+            value: proto_extension.value,
+        }
+    }
+}
+
+impl From<Extension> for ExtensionProto {
+    fn from(extension: Extension) -> Self {
+        ExtensionProto {
+            oid: extension.oid,
+            critical: extension.critical,
+            value: extension.value,
+        }
+    }
+}
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            subject: proto_op.subject.into_iter().map(Into::into).collect(),
+            extensions: proto_op.extensions.into_iter().map(Into::into).collect(),
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!(
+                        "alg field of create_certificate_signing_request::Operation message is empty."
+                    );
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            subject: op.subject.into_iter().map(Into::into).collect(),
+            extensions: op.extensions.into_iter().map(Into::into).collect(),
+            alg: Some(op.alg.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            csr: proto_result.csr,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto { csr: result.csr })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::create_certificate_signing_request::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::create_certificate_signing_request::{
+        Extension, NameAttribute, Operation, Result,
+    };
+    use crate::operations::psa_algorithm::{AsymmetricSignature, Hash, SignHash};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: "test name".to_string(),
+            subject: vec![
+                NameAttribute {
+                    oid: "2.5.4.10".to_string(),
+                    value: "Example Org".to_string(),
+                },
+                NameAttribute {
+                    oid: "2.5.4.3".to_string(),
+                    value: "device-0001".to_string(),
+                },
+            ],
+            extensions: vec![Extension {
+                oid: "2.5.29.15".to_string(),
+                critical: true,
+                value: vec![0x03, 0x02, 0x07, 0x80],
+            }],
+            alg: AsymmetricSignature::Ecdsa {
+                hash_alg: SignHash::Specific(Hash::Sha256),
+            },
+        }
+    }
+
+    #[test]
+    fn csr_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert_eq!(proto.key_name, "test name");
+        assert_eq!(proto.subject.len(), 2);
+        assert_eq!(proto.subject[1].oid, "2.5.4.3");
+        assert_eq!(proto.extensions.len(), 1);
+        assert!(proto.extensions[0].critical);
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.key_name, "test name");
+        assert_eq!(op.subject, get_op().subject);
+        assert_eq!(op.extensions, get_op().extensions);
+        assert_eq!(op.alg, get_op().alg);
+    }
+
+    #[test]
+    fn csr_empty_alg() {
+        let mut proto: OperationProto = Default::default();
+        proto.key_name = "test name".to_string();
+
+        let op: std::result::Result<Operation, _> = proto.try_into();
+
+        assert!(op.is_err());
+    }
+
+    #[test]
+    fn csr_resp_round_trip() {
+        let result = Result {
+            csr: vec![0x30, 0x82, 0x01, 0x0a],
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        assert_eq!(proto.csr, vec![0x30, 0x82, 0x01, 0x0a]);
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(result.csr, vec![0x30, 0x82, 0x01, 0x0a]);
+    }
+
+    #[test]
+    fn op_csr_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::CreateCertificateSigningRequest(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::CreateCertificateSigningRequest)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_csr_e2e() {
+        let result = Result {
+            csr: vec![0x30, 0x82, 0x01, 0x0a],
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::CreateCertificateSigningRequest(result))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::CreateCertificateSigningRequest)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::CreateCertificateSigningRequest)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::CreateCertificateSigningRequest)
+            .is_err());
+    }
+}
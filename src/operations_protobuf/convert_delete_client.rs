@@ -0,0 +1,108 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::delete_client::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::delete_client::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            client: proto_op.client,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto { client: op.client })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::delete_client::Operation as OperationProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::delete_client::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn delete_client_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let client = "some client".to_string();
+        proto.client = client.clone();
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.client, client);
+    }
+
+    #[test]
+    fn delete_client_op_to_proto() {
+        let client = "some client".to_string();
+        let op = Operation {
+            client: client.clone(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.client, client);
+    }
+
+    #[test]
+    fn op_delete_client_e2e() {
+        let op = Operation {
+            client: "some client".to_string(),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::DeleteClient(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::DeleteClient)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_delete_client_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::DeleteClient(Result {}))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::DeleteClient).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::DeleteClient)
+            .is_err());
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+// Protobuf imports
+use super::generated_ops::key_attestation::attestation_mechanism::{
+    Tpm2Certify as Tpm2CertifyProto, Variant,
+};
+use super::generated_ops::key_attestation::AttestationMechanism as AttestationMechanismProto;
+
+// Native imports
+use crate::operations::key_attestation::AttestationMechanism;
+
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+use std::convert::TryFrom;
+
+// Attestation mechanisms: from protobuf to native
+impl TryFrom<AttestationMechanismProto> for AttestationMechanism {
+    type Error = ResponseStatus;
+
+    fn try_from(mechanism: AttestationMechanismProto) -> Result<Self> {
+        match mechanism.variant.ok_or_else(|| {
+            error!("variant field of AttestationMechanism message is empty.");
+            ResponseStatus::InvalidEncoding
+        })? {
+            Variant::Tpm2Certify(_) => Ok(AttestationMechanism::Tpm2Certify),
+            Variant::VendorSpecific(id) => Ok(AttestationMechanism::VendorSpecific(id)),
+        }
+    }
+}
+
+// Attestation mechanisms: from native to protobuf
+impl TryFrom<AttestationMechanism> for AttestationMechanismProto {
+    type Error = ResponseStatus;
+
+    fn try_from(mechanism: AttestationMechanism) -> Result<Self> {
+        let variant = match mechanism {
+            AttestationMechanism::Tpm2Certify => Variant::Tpm2Certify(Tpm2CertifyProto {}),
+            AttestationMechanism::VendorSpecific(id) => Variant::VendorSpecific(id),
+        };
+        Ok(AttestationMechanismProto {
+            variant: Some(variant),
+        })
+    }
+}
+
+// An empty attesting key name on the wire means that the default attesting key is used.
+pub(super) fn attesting_key_name_from_proto(name: String) -> Option<String> {
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::key_attestation::AttestationMechanism as AttestationMechanismProto;
+    use crate::operations::key_attestation::AttestationMechanism;
+    use std::convert::TryInto;
+
+    #[test]
+    fn mechanism_round_trip() {
+        for mechanism in &[
+            AttestationMechanism::Tpm2Certify,
+            AttestationMechanism::VendorSpecific(0x1234),
+        ] {
+            let proto: AttestationMechanismProto =
+                (*mechanism).try_into().expect("Failed to convert");
+            let native: AttestationMechanism = proto.try_into().expect("Failed to convert");
+            assert_eq!(native, *mechanism);
+        }
+    }
+
+    #[test]
+    fn empty_mechanism() {
+        let proto: AttestationMechanismProto = Default::default();
+        let native: Result<AttestationMechanism, _> = proto.try_into();
+        assert!(native.is_err());
+    }
+}
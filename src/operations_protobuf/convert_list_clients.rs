@@ -0,0 +1,125 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::list_clients::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::list_clients::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {})
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(Default::default())
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            clients: proto_op.clients,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            clients: op.clients,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    // Operation <-> Proto conversions are not tested since they're too simple
+    use super::super::generated_ops::list_clients::Result as ResultProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::list_clients::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        proto.clients.push(String::from("client 1"));
+        proto.clients.push(String::from("client 2"));
+
+        let resp: Result = proto.try_into().unwrap();
+
+        assert_eq!(resp.clients, vec!["client 1", "client 2"]);
+    }
+
+    #[test]
+    fn resp_to_proto() {
+        let resp = Result {
+            clients: vec![String::from("client 1"), String::from("client 2")],
+        };
+
+        let proto: ResultProto = resp.try_into().unwrap();
+
+        assert_eq!(proto.clients, vec!["client 1", "client 2"]);
+    }
+
+    #[test]
+    fn op_list_clients_e2e() {
+        let req_body = CONVERTER
+            .operation_to_body(NativeOperation::ListClients(Operation {}))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::ListClients)
+            .is_ok());
+    }
+
+    #[test]
+    fn list_clients_result_e2e() {
+        let result = Result {
+            clients: vec![String::from("client 1")],
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::ListClients(result))
+            .expect("Failed to convert response");
+
+        match CONVERTER
+            .body_to_result(body, Opcode::ListClients)
+            .expect("Failed to convert back to result")
+        {
+            NativeResult::ListClients(result) => assert_eq!(result.clients, vec!["client 1"]),
+            _ => panic!("Expected list_clients"),
+        }
+    }
+
+    #[test]
+    fn req_from_native_mangled_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::ListClients)
+            .is_err());
+    }
+
+    #[test]
+    fn resp_from_native_mangled_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::ListClients)
+            .is_err());
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::list_keys::{
+    KeyInfo as KeyInfoProto, Operation as OperationProto, ProviderFilter as ProviderFilterProto,
+    Result as ResultProto,
+};
+use crate::operations::list_keys::{KeyInfo, Operation, Result};
+use crate::requests::{ProviderID, ResponseStatus};
+use log::error;
+use num::FromPrimitive;
+use std::convert::{TryFrom, TryInto};
+
+fn provider_id_from_u32(id: u32) -> std::result::Result<ProviderID, ResponseStatus> {
+    match FromPrimitive::from_u32(id) {
+        Some(id) => Ok(id),
+        None => Err(ResponseStatus::ProviderDoesNotExist),
+    }
+}
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            provider_id: match proto_op.provider_filter {
+                Some(filter) => Some(provider_id_from_u32(filter.id)?),
+                None => None,
+            },
+            key_type: match proto_op.key_type {
+                Some(key_type) => Some(key_type.try_into()?),
+                None => None,
+            },
+            usage_flags: match proto_op.usage_flags {
+                Some(usage_flags) => Some(usage_flags.try_into()?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            provider_filter: op
+                .provider_id
+                .map(|id| ProviderFilterProto { id: id as u32 }),
+            key_type: match op.key_type {
+                Some(key_type) => Some(key_type.try_into()?),
+                None => None,
+            },
+            usage_flags: match op.usage_flags {
+                Some(usage_flags) => Some(usage_flags.try_into()?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl TryFrom<KeyInfoProto> for KeyInfo {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_info: KeyInfoProto) -> std::result::Result<Self, Self::Error> {
+        Ok(KeyInfo {
+            provider_id: provider_id_from_u32(proto_info.provider_id)?,
+            name: proto_info.name,
+            attributes: proto_info
+                .attributes
+                .ok_or_else(|| {
+                    error!("attributes field of list_keys::KeyInfo message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<KeyInfo> for KeyInfoProto {
+    type Error = ResponseStatus;
+
+    fn try_from(info: KeyInfo) -> std::result::Result<Self, Self::Error> {
+        Ok(KeyInfoProto {
+            provider_id: info.provider_id as u32,
+            name: info.name,
+            attributes: Some(info.attributes.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: ResultProto) -> std::result::Result<Self, Self::Error> {
+        let mut keys: Vec<KeyInfo> = Vec::new();
+        for key in proto_op.keys {
+            keys.push(key.try_into()?);
+        }
+
+        Ok(Result { keys })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Result) -> std::result::Result<Self, Self::Error> {
+        let mut keys: Vec<KeyInfoProto> = Vec::new();
+        for key in op.keys {
+            keys.push(key.try_into()?);
+        }
+
+        Ok(ResultProto { keys })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::list_keys::{
+        KeyInfo as KeyInfoProto, Operation as OperationProto,
+        ProviderFilter as ProviderFilterProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::list_keys::{KeyInfo, Operation, Result};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{
+        request::RequestBody, response::ResponseBody, Opcode, ProviderID, ResponseStatus,
+    };
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_key_info() -> KeyInfo {
+        KeyInfo {
+            provider_id: ProviderID::MbedCrypto,
+            name: String::from("some key"),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::RsaKeyPair,
+                bits: 1024,
+                policy: Policy {
+                    usage_flags: UsageFlags {
+                        export: false,
+                        copy: false,
+                        cache: false,
+                        encrypt: false,
+                        decrypt: false,
+                        sign_message: true,
+                        verify_message: true,
+                        sign_hash: true,
+                        verify_hash: true,
+                        derive: false,
+                    },
+                    permitted_algorithms: Algorithm::AsymmetricSignature(
+                        AsymmetricSignature::RsaPkcs1v15Sign {
+                            hash_alg: Hash::Sha256.into(),
+                        },
+                    ),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn list_keys_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        proto.provider_filter = Some(ProviderFilterProto { id: 3 });
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.provider_id, Some(ProviderID::Tpm));
+        assert!(op.key_type.is_none());
+        assert!(op.usage_flags.is_none());
+    }
+
+    #[test]
+    fn list_keys_proto_with_wrong_provider() {
+        let mut proto: OperationProto = Default::default();
+        proto.provider_filter = Some(ProviderFilterProto { id: 0xff });
+
+        let status = TryInto::<Operation>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::ProviderDoesNotExist);
+    }
+
+    #[test]
+    fn list_keys_op_round_trip() {
+        let op = Operation {
+            provider_id: None,
+            key_type: Some(Type::RsaKeyPair),
+            usage_flags: Some(get_key_info().attributes.policy.usage_flags),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        assert!(proto.provider_filter.is_none());
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.provider_id, None);
+        assert_eq!(op.key_type, Some(Type::RsaKeyPair));
+        assert_eq!(
+            op.usage_flags,
+            Some(get_key_info().attributes.policy.usage_flags)
+        );
+    }
+
+    #[test]
+    fn list_keys_resp_round_trip() {
+        let result = Result {
+            keys: vec![get_key_info()],
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        assert_eq!(proto.keys.len(), 1);
+        assert_eq!(proto.keys[0].provider_id, 1);
+        assert_eq!(proto.keys[0].name, "some key");
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.keys, vec![get_key_info()]);
+    }
+
+    #[test]
+    fn list_keys_proto_with_no_attributes() {
+        let mut proto: ResultProto = Default::default();
+        proto.keys.push(KeyInfoProto {
+            provider_id: 1,
+            name: String::from("some key"),
+            attributes: None,
+        });
+
+        let status = TryInto::<Result>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn op_list_keys_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::ListKeys(Operation::default()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER.body_to_operation(body, Opcode::ListKeys).is_ok());
+    }
+
+    #[test]
+    fn resp_list_keys_e2e() {
+        let result = Result {
+            keys: vec![get_key_info()],
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::ListKeys(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::ListKeys).is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::ListKeys)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::ListKeys)
+            .is_err());
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_key_attestation::attesting_key_name_from_proto;
+use super::generated_ops::prepare_key_attestation::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::prepare_key_attestation::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            attested_key_name: proto_op.attested_key_name,
+            mechanism: proto_op
+                .mechanism
+                .ok_or_else(|| {
+                    error!(
+                        "mechanism field of prepare_key_attestation::Operation message is empty."
+                    );
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            attesting_key_name: attesting_key_name_from_proto(proto_op.attesting_key_name),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            attested_key_name: op.attested_key_name,
+            mechanism: Some(op.mechanism.try_into()?),
+            attesting_key_name: op.attesting_key_name.unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            preparation_data: proto_result.preparation_data,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            preparation_data: result.preparation_data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::prepare_key_attestation::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::key_attestation::AttestationMechanism;
+    use crate::operations::prepare_key_attestation::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            attested_key_name: "test name".to_string(),
+            mechanism: AttestationMechanism::Tpm2Certify,
+            attesting_key_name: Some("attesting key".to_string()),
+        }
+    }
+
+    #[test]
+    fn prepare_key_attestation_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert_eq!(proto.attested_key_name, "test name");
+        assert_eq!(proto.attesting_key_name, "attesting key");
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.attested_key_name, "test name");
+        assert_eq!(op.mechanism, AttestationMechanism::Tpm2Certify);
+        assert_eq!(op.attesting_key_name, Some("attesting key".to_string()));
+    }
+
+    #[test]
+    fn prepare_key_attestation_default_attesting_key() {
+        let op = Operation {
+            attesting_key_name: None,
+            ..get_op()
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+        assert!(proto.attesting_key_name.is_empty());
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert!(op.attesting_key_name.is_none());
+    }
+
+    #[test]
+    fn prepare_key_attestation_empty_mechanism() {
+        let mut proto: OperationProto = Default::default();
+        proto.attested_key_name = "test name".to_string();
+
+        let op: std::result::Result<Operation, _> = proto.try_into();
+
+        assert!(op.is_err());
+    }
+
+    #[test]
+    fn prepare_key_attestation_resp_round_trip() {
+        let result = Result {
+            preparation_data: vec![0x11, 0x22, 0x33],
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        assert_eq!(proto.preparation_data, vec![0x11, 0x22, 0x33]);
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(result.preparation_data, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn op_prepare_key_attestation_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PrepareKeyAttestation(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PrepareKeyAttestation)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_prepare_key_attestation_e2e() {
+        let result = Result {
+            preparation_data: vec![0x11, 0x22, 0x33],
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PrepareKeyAttestation(result))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PrepareKeyAttestation)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PrepareKeyAttestation)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PrepareKeyAttestation)
+            .is_err());
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_decrypt::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_aead_decrypt::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use zeroize::Zeroizing;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let nonce = Zeroizing::new(proto_op.nonce);
+        let additional_data = Zeroizing::new(proto_op.additional_data);
+        let ciphertext = Zeroizing::new(proto_op.ciphertext);
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("alg field of psa_aead_decrypt::Operation message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            nonce,
+            additional_data,
+            ciphertext,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        let alg = Some(op.alg.try_into()?);
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg,
+            nonce: op.nonce.to_vec(),
+            additional_data: op.additional_data.to_vec(),
+            ciphertext: op.ciphertext.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            plaintext: proto_result.plaintext.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            plaintext: result.plaintext.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_decrypt::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::generated_ops::psa_algorithm as algorithm_proto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_decrypt::{Operation, Result};
+    use crate::operations::psa_algorithm::{Aead, AeadWithDefaultLengthTag};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: "test name".to_string(),
+            alg: Aead::AeadWithShortenedTag {
+                aead_alg: AeadWithDefaultLengthTag::Ccm,
+                tag_length: 8,
+            },
+            nonce: vec![0xa5; 13].into(),
+            additional_data: vec![0x44, 0x55].into(),
+            ciphertext: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    #[test]
+    fn aead_decrypt_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let nonce = vec![0xa5; 12];
+        let additional_data = vec![0x44, 0x55];
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        proto.nonce = nonce.clone();
+        proto.additional_data = additional_data.clone();
+        proto.ciphertext = ciphertext.clone();
+        proto.key_name = key_name.clone();
+        proto.alg = Some(algorithm_proto::algorithm::Aead {
+            variant: Some(
+                algorithm_proto::algorithm::aead::Variant::AeadWithDefaultLengthTag(
+                    algorithm_proto::algorithm::aead::AeadWithDefaultLengthTag::Gcm.into(),
+                ),
+            ),
+        });
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.nonce, nonce.into());
+        assert_eq!(op.additional_data, additional_data.into());
+        assert_eq!(op.ciphertext, ciphertext.into());
+        assert_eq!(op.key_name, key_name);
+        assert_eq!(
+            op.alg,
+            Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Gcm)
+        );
+    }
+
+    #[test]
+    fn aead_decrypt_proto_with_no_alg() {
+        let mut proto: OperationProto = Default::default();
+        proto.key_name = "test name".to_string();
+
+        let status = TryInto::<Operation>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn aead_decrypt_op_to_proto() {
+        let op = get_op();
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.nonce, vec![0xa5; 13]);
+        assert_eq!(proto.additional_data, vec![0x44, 0x55]);
+        assert_eq!(proto.ciphertext, vec![0x11, 0x22, 0x33]);
+        assert_eq!(proto.key_name, "test name");
+        assert!(proto.alg.is_some());
+    }
+
+    #[test]
+    fn aead_decrypt_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        let plaintext = vec![0x11, 0x22, 0x33];
+        proto.plaintext = plaintext.clone();
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.plaintext, plaintext.into());
+    }
+
+    #[test]
+    fn aead_decrypt_resp_to_proto() {
+        let plaintext = vec![0x11, 0x22, 0x33];
+        let result = Result {
+            plaintext: plaintext.clone().into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.plaintext, plaintext);
+    }
+
+    #[test]
+    fn op_aead_decrypt_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadDecrypt(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadDecrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_aead_decrypt_e2e() {
+        let result = Result {
+            plaintext: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadDecrypt(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadDecrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaAeadDecrypt)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaAeadDecrypt)
+            .is_err());
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_decrypt_setup::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_aead_decrypt_setup::{Operation, Result};
+use crate::requests::{ResponseStatus, SessionHandle};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("alg field of psa_aead_decrypt_setup::Operation message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            nonce: proto_op.nonce.into(),
+            additional_data: proto_op.additional_data.into(),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg: Some(op.alg.try_into()?),
+            nonce: op.nonce.to_vec(),
+            additional_data: op.additional_data.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            session: SessionHandle::new(proto_result.session).ok_or_else(|| {
+                error!("session field of psa_aead_decrypt_setup::Result message is 0.");
+                ResponseStatus::InvalidEncoding
+            })?,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            session: result.session.value(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_decrypt_setup::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_decrypt_setup::{Operation, Result};
+    use crate::operations::psa_algorithm::{Aead, AeadWithDefaultLengthTag};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, SessionHandle};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: "test name".to_string(),
+            alg: Aead::AeadWithDefaultLengthTag(AeadWithDefaultLengthTag::Gcm),
+            nonce: vec![0x11, 0x22, 0x33].into(),
+            additional_data: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    fn get_result() -> Result {
+        Result {
+            session: SessionHandle::new(0x1234).unwrap(),
+        }
+    }
+
+    #[test]
+    fn aead_decrypt_setup_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert_eq!(proto.key_name, "test name");
+        assert!(proto.alg.is_some());
+        assert_eq!(proto.nonce, vec![0x11, 0x22, 0x33]);
+        assert_eq!(proto.additional_data, vec![0x11, 0x22, 0x33]);
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.key_name, get_op().key_name);
+        assert_eq!(op.alg, get_op().alg);
+        assert_eq!(op.nonce, get_op().nonce);
+        assert_eq!(op.additional_data, get_op().additional_data);
+    }
+
+    #[test]
+    fn aead_decrypt_setup_empty_alg() {
+        let proto: OperationProto = Default::default();
+        let op: std::result::Result<Operation, _> = proto.try_into();
+        assert!(op.is_err());
+    }
+
+    #[test]
+    fn aead_decrypt_setup_resp_round_trip() {
+        let proto: ResultProto = get_result().try_into().expect("Failed to convert");
+        assert_eq!(proto.session, 0x1234);
+
+        let resp: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(resp.session, get_result().session);
+    }
+
+    #[test]
+    fn aead_decrypt_setup_null_session() {
+        let proto: ResultProto = Default::default();
+        let resp: std::result::Result<Result, _> = proto.try_into();
+        assert!(resp.is_err());
+    }
+
+    #[test]
+    fn op_aead_decrypt_setup_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadDecryptSetup(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadDecryptSetup)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_aead_decrypt_setup_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadDecryptSetup(get_result()))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadDecryptSetup)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaAeadDecryptSetup)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaAeadDecryptSetup)
+            .is_err());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_finish::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_aead_finish::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {})
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(Default::default())
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            ciphertext: proto_result.ciphertext.into(),
+            tag: proto_result.tag.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            ciphertext: result.ciphertext.to_vec(),
+            tag: result.tag.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_finish::Result as ResultProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_finish::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_result() -> Result {
+        Result {
+            ciphertext: vec![0x11, 0x22, 0x33].into(),
+            tag: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    #[test]
+    fn aead_finish_resp_round_trip() {
+        let proto: ResultProto = get_result().try_into().expect("Failed to convert");
+        assert_eq!(proto.ciphertext, vec![0x11, 0x22, 0x33]);
+        assert_eq!(proto.tag, vec![0x11, 0x22, 0x33]);
+
+        let resp: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(resp.ciphertext, get_result().ciphertext);
+        assert_eq!(resp.tag, get_result().tag);
+    }
+
+    #[test]
+    fn op_aead_finish_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadFinish(Operation {}))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadFinish)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_aead_finish_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadFinish(get_result()))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadFinish)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaAeadFinish)
+            .is_err());
+    }
+}
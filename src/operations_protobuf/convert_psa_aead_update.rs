@@ -0,0 +1,131 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_aead_update::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_aead_update::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            input: proto_op.input.into(),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            input: op.input.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            output: proto_result.output.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            output: result.output.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_aead_update::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_aead_update::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            input: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    fn get_result() -> Result {
+        Result {
+            output: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    #[test]
+    fn aead_update_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert_eq!(proto.input, vec![0x11, 0x22, 0x33]);
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.input, get_op().input);
+    }
+
+    #[test]
+    fn aead_update_resp_round_trip() {
+        let proto: ResultProto = get_result().try_into().expect("Failed to convert");
+        assert_eq!(proto.output, vec![0x11, 0x22, 0x33]);
+
+        let resp: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(resp.output, get_result().output);
+    }
+
+    #[test]
+    fn op_aead_update_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAeadUpdate(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaAeadUpdate)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_aead_update_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAeadUpdate(get_result()))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAeadUpdate)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaAeadUpdate)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaAeadUpdate)
+            .is_err());
+    }
+}
@@ -67,7 +67,7 @@ impl TryFrom<HashProto> for Hash {
 }
 
 // Hash algorithms: from native to protobuf
-fn hash_to_i32(hash: Hash) -> i32 {
+pub(super) fn hash_to_i32(hash: Hash) -> i32 {
     match hash {
         #[allow(deprecated)]
         Hash::Md2 => HashProto::Md2.into(),
@@ -236,7 +236,7 @@ impl TryFrom<CipherProto> for Cipher {
 }
 
 // Cipher algorithms: from native to protobuf
-fn cipher_to_i32(cipher: Cipher) -> i32 {
+pub(super) fn cipher_to_i32(cipher: Cipher) -> i32 {
     match cipher {
         Cipher::StreamCipher => CipherProto::StreamCipher.into(),
         Cipher::Ctr => CipherProto::Ctr.into(),
@@ -489,7 +489,7 @@ impl TryFrom<RawKeyAgreementProto> for RawKeyAgreement {
 }
 
 // RawKeyAgreement algorithms: from native to protobuf
-fn raw_key_agreement_to_i32(raw_key_agreement: RawKeyAgreement) -> i32 {
+pub(super) fn raw_key_agreement_to_i32(raw_key_agreement: RawKeyAgreement) -> i32 {
     match raw_key_agreement {
         RawKeyAgreement::Ffdh => RawKeyAgreementProto::Ffdh.into(),
         RawKeyAgreement::Ecdh => RawKeyAgreementProto::Ecdh.into(),
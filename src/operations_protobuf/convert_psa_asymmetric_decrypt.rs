@@ -0,0 +1,242 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_asymmetric_decrypt::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_asymmetric_decrypt::{Operation, Result};
+use crate::requests::ResponseStatus;
+use crate::secrecy::{ExposeSecret, Secret};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use zeroize::Zeroizing;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let ciphertext = Zeroizing::new(proto_op.ciphertext);
+        // An empty salt on the wire means that no salt was given.
+        let salt = if proto_op.salt.is_empty() {
+            None
+        } else {
+            Some(Zeroizing::new(proto_op.salt))
+        };
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("alg field of psa_asymmetric_decrypt::Operation message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            ciphertext,
+            salt,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        let alg = Some(op.alg.try_into()?);
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg,
+            ciphertext: op.ciphertext.to_vec(),
+            salt: op.salt.map(|salt| salt.to_vec()).unwrap_or_default(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            plaintext: Secret::new(proto_result.plaintext),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            plaintext: result.plaintext.expose_secret().to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm as algorithm_proto;
+    use super::super::generated_ops::psa_asymmetric_decrypt::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{AsymmetricEncryption, Hash};
+    use crate::operations::psa_asymmetric_decrypt::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use crate::secrecy::{ExposeSecret, Secret};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn asym_decrypt_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        proto.ciphertext = ciphertext.clone();
+        proto.key_name = key_name.clone();
+        proto.alg = Some(algorithm_proto::algorithm::AsymmetricEncryption {
+            variant: Some(
+                algorithm_proto::algorithm::asymmetric_encryption::Variant::RsaPkcs1v15Crypt(
+                    algorithm_proto::algorithm::asymmetric_encryption::RsaPkcs1v15Crypt {},
+                ),
+            ),
+        });
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.ciphertext, ciphertext.into());
+        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.alg, AsymmetricEncryption::RsaPkcs1v15Crypt);
+        assert!(op.salt.is_none());
+    }
+
+    #[test]
+    fn asym_decrypt_proto_with_salt_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let salt = vec![0xa5; 8];
+        proto.key_name = "test name".to_string();
+        proto.salt = salt.clone();
+        proto.alg = Some(algorithm_proto::algorithm::AsymmetricEncryption {
+            variant: Some(
+                algorithm_proto::algorithm::asymmetric_encryption::Variant::RsaOaep(
+                    algorithm_proto::algorithm::asymmetric_encryption::RsaOaep {
+                        hash_alg: algorithm_proto::algorithm::Hash::Sha256.into(),
+                    },
+                ),
+            ),
+        });
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.salt, Some(salt.into()));
+        assert_eq!(
+            op.alg,
+            AsymmetricEncryption::RsaOaep {
+                hash_alg: Hash::Sha256
+            }
+        );
+    }
+
+    #[test]
+    fn asym_decrypt_proto_with_no_alg() {
+        let mut proto: OperationProto = Default::default();
+        proto.key_name = "test name".to_string();
+
+        let status = TryInto::<Operation>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn asym_decrypt_op_to_proto() {
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        let salt = vec![0xa5; 8];
+        let key_name = "test name".to_string();
+        let op = Operation {
+            key_name: key_name.clone(),
+            alg: AsymmetricEncryption::RsaOaep {
+                hash_alg: Hash::Sha256,
+            },
+            ciphertext: ciphertext.clone().into(),
+            salt: Some(salt.clone().into()),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.ciphertext, ciphertext);
+        assert_eq!(proto.salt, salt);
+        assert_eq!(proto.key_name, key_name);
+    }
+
+    #[test]
+    fn asym_decrypt_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        let plaintext = vec![0x11, 0x22, 0x33];
+        proto.plaintext = plaintext.clone();
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.plaintext.expose_secret(), &plaintext);
+    }
+
+    #[test]
+    fn asym_decrypt_resp_to_proto() {
+        let plaintext = vec![0x11, 0x22, 0x33];
+        let result = Result {
+            plaintext: Secret::new(plaintext.clone()),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.plaintext, plaintext);
+    }
+
+    #[test]
+    fn op_asym_decrypt_e2e() {
+        let op = Operation {
+            key_name: "test name".to_string(),
+            alg: AsymmetricEncryption::RsaPkcs1v15Crypt,
+            ciphertext: vec![0x11, 0x22, 0x33].into(),
+            salt: None,
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaAsymmetricDecrypt(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaAsymmetricDecrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_asym_decrypt_e2e() {
+        let result = Result {
+            plaintext: Secret::new(vec![0x11, 0x22, 0x33]),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaAsymmetricDecrypt(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaAsymmetricDecrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaAsymmetricDecrypt)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaAsymmetricDecrypt)
+            .is_err());
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_algorithm::cipher_to_i32;
+use super::generated_ops::psa_algorithm::algorithm::Cipher as CipherProto;
+use super::generated_ops::psa_cipher_decrypt::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_cipher_decrypt::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::{TryFrom, TryInto};
+use zeroize::Zeroizing;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let iv = Zeroizing::new(proto_op.iv);
+        let ciphertext = Zeroizing::new(proto_op.ciphertext);
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: CipherProto::try_from(proto_op.alg)?.try_into()?,
+            iv,
+            ciphertext,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg: cipher_to_i32(op.alg),
+            iv: op.iv.to_vec(),
+            ciphertext: op.ciphertext.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            plaintext: proto_result.plaintext.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            plaintext: result.plaintext.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm::algorithm::Cipher as CipherProto;
+    use super::super::generated_ops::psa_cipher_decrypt::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::Cipher;
+    use crate::operations::psa_cipher_decrypt::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn cipher_decrypt_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let iv = vec![0xa5; 16];
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        proto.iv = iv.clone();
+        proto.ciphertext = ciphertext.clone();
+        proto.key_name = key_name.clone();
+        proto.alg = CipherProto::CbcPkcs7.into();
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.iv, iv.into());
+        assert_eq!(op.ciphertext, ciphertext.into());
+        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.alg, Cipher::CbcPkcs7);
+    }
+
+    #[test]
+    fn cipher_decrypt_op_to_proto() {
+        let iv = vec![0xa5; 16];
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        let op = Operation {
+            key_name: key_name.clone(),
+            alg: Cipher::Ctr,
+            iv: iv.clone().into(),
+            ciphertext: ciphertext.clone().into(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.iv, iv);
+        assert_eq!(proto.ciphertext, ciphertext);
+        assert_eq!(proto.key_name, key_name);
+        assert_eq!(proto.alg, CipherProto::Ctr as i32);
+    }
+
+    #[test]
+    fn cipher_decrypt_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        let plaintext = vec![0x11, 0x22, 0x33];
+        proto.plaintext = plaintext.clone();
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.plaintext, plaintext.into());
+    }
+
+    #[test]
+    fn cipher_decrypt_resp_to_proto() {
+        let plaintext = vec![0x11, 0x22, 0x33];
+        let result = Result {
+            plaintext: plaintext.clone().into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.plaintext, plaintext);
+    }
+
+    #[test]
+    fn op_cipher_decrypt_e2e() {
+        let op = Operation {
+            key_name: "test name".to_string(),
+            alg: Cipher::Ctr,
+            iv: vec![0xa5; 16].into(),
+            ciphertext: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaCipherDecrypt(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaCipherDecrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_cipher_decrypt_e2e() {
+        let result = Result {
+            plaintext: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaCipherDecrypt(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaCipherDecrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaCipherDecrypt)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaCipherDecrypt)
+            .is_err());
+    }
+}
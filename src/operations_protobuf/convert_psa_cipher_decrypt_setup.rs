@@ -0,0 +1,158 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_algorithm::cipher_to_i32;
+use super::generated_ops::psa_algorithm::algorithm::Cipher as CipherProto;
+use super::generated_ops::psa_cipher_decrypt_setup::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_cipher_decrypt_setup::{Operation, Result};
+use crate::requests::{ResponseStatus, SessionHandle};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: CipherProto::try_from(proto_op.alg)?.try_into()?,
+            iv: proto_op.iv.into(),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg: cipher_to_i32(op.alg),
+            iv: op.iv.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            session: SessionHandle::new(proto_result.session).ok_or_else(|| {
+                error!("session field of psa_cipher_decrypt_setup::Result message is 0.");
+                ResponseStatus::InvalidEncoding
+            })?,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            session: result.session.value(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm::algorithm::Cipher as CipherProto;
+    use super::super::generated_ops::psa_cipher_decrypt_setup::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::Cipher;
+    use crate::operations::psa_cipher_decrypt_setup::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, SessionHandle};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: "test name".to_string(),
+            alg: Cipher::Ctr,
+            iv: vec![0x11, 0x22, 0x33].into(),
+        }
+    }
+
+    fn get_result() -> Result {
+        Result {
+            session: SessionHandle::new(0x1234).unwrap(),
+        }
+    }
+
+    #[test]
+    fn cipher_decrypt_setup_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert_eq!(proto.key_name, "test name");
+        assert_eq!(proto.alg, CipherProto::Ctr as i32);
+        assert_eq!(proto.iv, vec![0x11, 0x22, 0x33]);
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.key_name, get_op().key_name);
+        assert_eq!(op.alg, get_op().alg);
+        assert_eq!(op.iv, get_op().iv);
+    }
+
+    #[test]
+    fn cipher_decrypt_setup_resp_round_trip() {
+        let proto: ResultProto = get_result().try_into().expect("Failed to convert");
+        assert_eq!(proto.session, 0x1234);
+
+        let resp: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(resp.session, get_result().session);
+    }
+
+    #[test]
+    fn cipher_decrypt_setup_null_session() {
+        let proto: ResultProto = Default::default();
+        let resp: std::result::Result<Result, _> = proto.try_into();
+        assert!(resp.is_err());
+    }
+
+    #[test]
+    fn op_cipher_decrypt_setup_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaCipherDecryptSetup(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaCipherDecryptSetup)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_cipher_decrypt_setup_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaCipherDecryptSetup(get_result()))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaCipherDecryptSetup)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaCipherDecryptSetup)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaCipherDecryptSetup)
+            .is_err());
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_algorithm::cipher_to_i32;
+use super::generated_ops::psa_algorithm::algorithm::Cipher as CipherProto;
+use super::generated_ops::psa_cipher_encrypt::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_cipher_encrypt::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::{TryFrom, TryInto};
+use zeroize::Zeroizing;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let plaintext = Zeroizing::new(proto_op.plaintext);
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: CipherProto::try_from(proto_op.alg)?.try_into()?,
+            plaintext,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg: cipher_to_i32(op.alg),
+            plaintext: op.plaintext.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            iv: proto_result.iv.into(),
+            ciphertext: proto_result.ciphertext.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            iv: result.iv.to_vec(),
+            ciphertext: result.ciphertext.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm::algorithm::Cipher as CipherProto;
+    use super::super::generated_ops::psa_cipher_encrypt::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::Cipher;
+    use crate::operations::psa_cipher_encrypt::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn cipher_encrypt_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let plaintext = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        proto.plaintext = plaintext.clone();
+        proto.key_name = key_name.clone();
+        proto.alg = CipherProto::CbcPkcs7.into();
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.plaintext, plaintext.into());
+        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.alg, Cipher::CbcPkcs7);
+    }
+
+    #[test]
+    fn cipher_encrypt_proto_with_no_alg() {
+        let mut proto: OperationProto = Default::default();
+        proto.key_name = "test name".to_string();
+
+        let status = TryInto::<Operation>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn cipher_encrypt_op_to_proto() {
+        let plaintext = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        let op = Operation {
+            key_name: key_name.clone(),
+            alg: Cipher::Ctr,
+            plaintext: plaintext.clone().into(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.plaintext, plaintext);
+        assert_eq!(proto.key_name, key_name);
+        assert_eq!(proto.alg, CipherProto::Ctr as i32);
+    }
+
+    #[test]
+    fn cipher_encrypt_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        let iv = vec![0xa5; 16];
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        proto.iv = iv.clone();
+        proto.ciphertext = ciphertext.clone();
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.iv, iv.into());
+        assert_eq!(result.ciphertext, ciphertext.into());
+    }
+
+    #[test]
+    fn cipher_encrypt_resp_to_proto() {
+        let iv = vec![0xa5; 16];
+        let ciphertext = vec![0x11, 0x22, 0x33];
+        let result = Result {
+            iv: iv.clone().into(),
+            ciphertext: ciphertext.clone().into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.iv, iv);
+        assert_eq!(proto.ciphertext, ciphertext);
+    }
+
+    #[test]
+    fn op_cipher_encrypt_e2e() {
+        let op = Operation {
+            key_name: "test name".to_string(),
+            alg: Cipher::Ctr,
+            plaintext: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaCipherEncrypt(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaCipherEncrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_cipher_encrypt_e2e() {
+        let result = Result {
+            iv: vec![0xa5; 16].into(),
+            ciphertext: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaCipherEncrypt(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaCipherEncrypt)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaCipherEncrypt)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaCipherEncrypt)
+            .is_err());
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_copy_key::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_copy_key::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            new_key_name: proto_op.new_key_name,
+            attributes: proto_op
+                .attributes
+                .ok_or_else(|| {
+                    error!("attributes field of psa_copy_key::Operation message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+            new_key_name: op.new_key_name,
+            attributes: Some(op.attributes.try_into()?),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_copy_key::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{Algorithm, Cipher};
+    use crate::operations::psa_copy_key::{Operation, Result};
+    use crate::operations::psa_key_attributes::{Attributes, Lifetime, Policy, Type, UsageFlags};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: "test name".to_string(),
+            new_key_name: "new test name".to_string(),
+            attributes: Attributes {
+                lifetime: Lifetime::Persistent,
+                key_type: Type::Aes,
+                bits: 128,
+                policy: Policy {
+                    usage_flags: UsageFlags {
+                        export: false,
+                        copy: false,
+                        cache: false,
+                        encrypt: true,
+                        decrypt: false,
+                        sign_message: false,
+                        verify_message: false,
+                        sign_hash: false,
+                        verify_hash: false,
+                        derive: false,
+                    },
+                    permitted_algorithms: Algorithm::Cipher(Cipher::Ctr),
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn copy_key_op_round_trip() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+        assert_eq!(proto.key_name, "test name");
+        assert_eq!(proto.new_key_name, "new test name");
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+        assert_eq!(op.key_name, "test name");
+        assert_eq!(op.new_key_name, "new test name");
+        assert_eq!(op.attributes, get_op().attributes);
+    }
+
+    #[test]
+    fn copy_key_proto_with_no_attributes() {
+        let mut proto: OperationProto = Default::default();
+        proto.key_name = "test name".to_string();
+
+        let status = TryInto::<Operation>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn copy_key_res_round_trip() {
+        let proto: ResultProto = Result {}.try_into().expect("Failed to convert");
+        let _res: Result = proto.try_into().expect("Failed to convert");
+    }
+
+    #[test]
+    fn op_copy_key_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaCopyKey(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaCopyKey)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_copy_key_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaCopyKey(Result {}))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::PsaCopyKey).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaCopyKey)
+            .is_err());
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_generate_random::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_generate_random::{Operation, Result, MAX_SIZE};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let size: usize = proto_op.size.try_into().or_else(|e| {
+            error!(
+                "size field of psa_generate_random::Operation can not be represented by an usize ({}).",
+                e
+            );
+            Err(ResponseStatus::InvalidEncoding)
+        })?;
+        if size > MAX_SIZE {
+            error!(
+                "size field of psa_generate_random::Operation ({}) is larger than the maximum ({}).",
+                size, MAX_SIZE
+            );
+            return Err(ResponseStatus::PsaErrorInsufficientMemory);
+        }
+
+        Ok(Operation { size })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            size: op.size.try_into().or_else(|e| {
+                error!(
+                    "size field of psa_generate_random::Operation can not be represented by an u64 ({}).",
+                    e
+                );
+                Err(ResponseStatus::InvalidEncoding)
+            })?,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            random_bytes: proto_result.random_bytes.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            random_bytes: result.random_bytes.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_generate_random::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_generate_random::{Operation, Result, MAX_SIZE};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn generate_random_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        proto.size = 32;
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.size, 32);
+    }
+
+    #[test]
+    fn generate_random_op_to_proto() {
+        let op = Operation { size: 32 };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.size, 32);
+    }
+
+    #[test]
+    fn generate_random_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        let random_bytes = vec![0x11, 0x22, 0x33];
+        proto.random_bytes = random_bytes.clone();
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.random_bytes, random_bytes.into());
+    }
+
+    #[test]
+    fn generate_random_resp_to_proto() {
+        let random_bytes = vec![0x11, 0x22, 0x33];
+        let result = Result {
+            random_bytes: random_bytes.clone().into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.random_bytes, random_bytes);
+    }
+
+    #[test]
+    fn op_generate_random_e2e() {
+        let op = Operation { size: 32 };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaGenerateRandom(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaGenerateRandom)
+            .is_ok());
+    }
+
+    #[test]
+    fn op_generate_random_max_size() {
+        let op = Operation { size: MAX_SIZE };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaGenerateRandom(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaGenerateRandom)
+            .is_ok());
+    }
+
+    #[test]
+    fn op_generate_random_over_max_size() {
+        let op = Operation { size: MAX_SIZE + 1 };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaGenerateRandom(op))
+            .expect("Failed to convert request");
+
+        assert_eq!(
+            CONVERTER
+                .body_to_operation(body, Opcode::PsaGenerateRandom)
+                .unwrap_err(),
+            ResponseStatus::PsaErrorInsufficientMemory
+        );
+    }
+
+    #[test]
+    fn resp_generate_random_e2e() {
+        let result = Result {
+            random_bytes: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaGenerateRandom(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaGenerateRandom)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaGenerateRandom)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaGenerateRandom)
+            .is_err());
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_get_key_attributes::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_get_key_attributes::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            key_name: proto_op.key_name,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            key_name: op.key_name,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            attributes: proto_result
+                .attributes
+                .ok_or_else(|| {
+                    error!("attributes field of psa_get_key_attributes::Result message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            attributes: Some(result.attributes.try_into()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_get_key_attributes::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{Algorithm, AsymmetricSignature, Hash};
+    use crate::operations::psa_get_key_attributes::{Operation, Result};
+    use crate::operations::psa_key_attributes::{
+        Attributes, EccFamily, Lifetime, Policy, Type, UsageFlags,
+    };
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_attrs() -> Attributes {
+        Attributes {
+            lifetime: Lifetime::Persistent,
+            key_type: Type::EccKeyPair {
+                curve_family: EccFamily::SecpR1,
+            },
+            bits: 256,
+            policy: Policy {
+                usage_flags: UsageFlags {
+                    export: false,
+                    copy: false,
+                    cache: false,
+                    encrypt: false,
+                    decrypt: false,
+                    sign_message: true,
+                    verify_message: false,
+                    sign_hash: true,
+                    verify_hash: false,
+                    derive: false,
+                },
+                permitted_algorithms: Algorithm::AsymmetricSignature(AsymmetricSignature::Ecdsa {
+                    hash_alg: Hash::Sha256.into(),
+                }),
+            },
+        }
+    }
+
+    #[test]
+    fn get_key_attributes_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let key_name = "test name".to_string();
+        proto.key_name = key_name.clone();
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.key_name, key_name);
+    }
+
+    #[test]
+    fn get_key_attributes_op_to_proto() {
+        let key_name = "test name".to_string();
+        let op = Operation {
+            key_name: key_name.clone(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.key_name, key_name);
+    }
+
+    #[test]
+    fn get_key_attributes_resp_round_trip() {
+        let result = Result {
+            attributes: get_attrs(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.attributes, get_attrs());
+    }
+
+    #[test]
+    fn get_key_attributes_proto_with_no_attributes() {
+        let proto: ResultProto = Default::default();
+
+        let status = TryInto::<Result>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn op_get_key_attributes_e2e() {
+        let op = Operation {
+            key_name: "test name".to_string(),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaGetKeyAttributes(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaGetKeyAttributes)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_get_key_attributes_e2e() {
+        let result = Result {
+            attributes: get_attrs(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaGetKeyAttributes(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaGetKeyAttributes)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaGetKeyAttributes)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaGetKeyAttributes)
+            .is_err());
+    }
+}
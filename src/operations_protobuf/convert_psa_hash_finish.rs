@@ -0,0 +1,109 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_hash_finish::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_hash_finish::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::TryFrom;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {})
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(Default::default())
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            hash: proto_result.hash.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            hash: result.hash.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_hash_finish::Result as ResultProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_hash_finish::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn hash_finish_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        proto.hash = vec![0x11, 0x22, 0x33];
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(*result.hash, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn hash_finish_resp_to_proto() {
+        let result = Result {
+            hash: vec![0x11, 0x22, 0x33].into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.hash, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn op_hash_finish_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaHashFinish(Operation {}))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaHashFinish)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_hash_finish_e2e() {
+        let result = Result {
+            hash: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaHashFinish(result))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaHashFinish)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaHashFinish)
+            .is_err());
+    }
+}
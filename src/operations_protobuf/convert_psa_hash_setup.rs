@@ -0,0 +1,150 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_algorithm::hash_to_i32;
+use super::generated_ops::psa_algorithm::algorithm::Hash as HashProto;
+use super::generated_ops::psa_hash_setup::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_hash_setup::{Operation, Result};
+use crate::requests::{ResponseStatus, SessionHandle};
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            alg: HashProto::try_from(proto_op.alg)?.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            alg: hash_to_i32(op.alg),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            session: SessionHandle::new(proto_result.session).ok_or_else(|| {
+                error!("session field of psa_hash_setup::Result message is 0.");
+                ResponseStatus::InvalidEncoding
+            })?,
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            session: result.session.value(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm::algorithm::Hash as HashProto;
+    use super::super::generated_ops::psa_hash_setup::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::Hash;
+    use crate::operations::psa_hash_setup::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, SessionHandle};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn hash_setup_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        proto.alg = HashProto::Sha256.into();
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.alg, Hash::Sha256);
+    }
+
+    #[test]
+    fn hash_setup_op_to_proto() {
+        let op = Operation { alg: Hash::Sha256 };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.alg, HashProto::Sha256 as i32);
+    }
+
+    #[test]
+    fn hash_setup_resp_to_proto_to_resp() {
+        let result = Result {
+            session: SessionHandle::new(0x1234).unwrap(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+        assert_eq!(proto.session, 0x1234);
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+        assert_eq!(result.session.value(), 0x1234);
+    }
+
+    #[test]
+    fn hash_setup_null_session() {
+        let proto: ResultProto = Default::default();
+        let result: std::result::Result<Result, _> = proto.try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn op_hash_setup_e2e() {
+        let op = Operation { alg: Hash::Sha256 };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaHashSetup(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaHashSetup)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_hash_setup_e2e() {
+        let result = Result {
+            session: SessionHandle::new(1).unwrap(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaHashSetup(result))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::PsaHashSetup).is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaHashSetup)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaHashSetup)
+            .is_err());
+    }
+}
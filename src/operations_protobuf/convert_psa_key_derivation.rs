@@ -0,0 +1,41 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+// Protobuf imports
+use super::generated_ops::psa_key_derivation::DerivationStep as DerivationStepProto;
+
+// Native imports
+use crate::operations::psa_key_derivation::DerivationStep;
+
+use crate::requests::{ResponseStatus, Result};
+use log::error;
+use std::convert::TryFrom;
+
+// Key derivation steps: from protobuf to native
+impl TryFrom<DerivationStepProto> for DerivationStep {
+    type Error = ResponseStatus;
+
+    fn try_from(step: DerivationStepProto) -> Result<Self> {
+        match step {
+            DerivationStepProto::None => {
+                error!("The None value of DerivationStep enumeration is not allowed.");
+                Err(ResponseStatus::InvalidEncoding)
+            }
+            DerivationStepProto::Secret => Ok(DerivationStep::Secret),
+            DerivationStepProto::Label => Ok(DerivationStep::Label),
+            DerivationStepProto::Salt => Ok(DerivationStep::Salt),
+            DerivationStepProto::Info => Ok(DerivationStep::Info),
+            DerivationStepProto::Seed => Ok(DerivationStep::Seed),
+        }
+    }
+}
+
+// Key derivation steps: from native to protobuf
+pub(super) fn derivation_step_to_i32(step: DerivationStep) -> i32 {
+    match step {
+        DerivationStep::Secret => DerivationStepProto::Secret.into(),
+        DerivationStep::Label => DerivationStepProto::Label.into(),
+        DerivationStep::Salt => DerivationStepProto::Salt.into(),
+        DerivationStep::Info => DerivationStepProto::Info.into(),
+        DerivationStep::Seed => DerivationStepProto::Seed.into(),
+    }
+}
@@ -0,0 +1,119 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_key_derivation::derivation_step_to_i32;
+use super::generated_ops::psa_key_derivation::DerivationStep as DerivationStepProto;
+use super::generated_ops::psa_key_derivation_input_bytes::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_key_derivation_input_bytes::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            step: DerivationStepProto::try_from(proto_op.step)?.try_into()?,
+            data: proto_op.data.into(),
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            step: derivation_step_to_i32(op.step),
+            data: op.data.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_key_derivation::DerivationStep as DerivationStepProto;
+    use super::super::generated_ops::psa_key_derivation_input_bytes::Operation as OperationProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_key_derivation::DerivationStep;
+    use crate::operations::psa_key_derivation_input_bytes::Operation;
+    use crate::operations::NativeOperation;
+    use crate::requests::{request::RequestBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn key_derivation_input_bytes_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        proto.step = DerivationStepProto::Salt.into();
+        proto.data = vec![0x11, 0x22, 0x33];
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.step, DerivationStep::Salt);
+        assert_eq!(*op.data, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn key_derivation_input_bytes_op_to_proto() {
+        let op = Operation {
+            step: DerivationStep::Info,
+            data: vec![0x11, 0x22, 0x33].into(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.step, DerivationStepProto::Info as i32);
+        assert_eq!(proto.data, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn key_derivation_input_bytes_no_step() {
+        let proto: OperationProto = Default::default();
+        let op: Result<Operation, _> = proto.try_into();
+        assert!(op.is_err());
+    }
+
+    #[test]
+    fn op_key_derivation_input_bytes_e2e() {
+        let op = Operation {
+            step: DerivationStep::Salt,
+            data: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaKeyDerivationInputBytes(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaKeyDerivationInputBytes)
+            .is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaKeyDerivationInputBytes)
+            .is_err());
+    }
+}
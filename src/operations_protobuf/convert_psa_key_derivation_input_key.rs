@@ -0,0 +1,112 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_key_derivation::derivation_step_to_i32;
+use super::generated_ops::psa_key_derivation::DerivationStep as DerivationStepProto;
+use super::generated_ops::psa_key_derivation_input_key::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_key_derivation_input_key::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            step: DerivationStepProto::try_from(proto_op.step)?.try_into()?,
+            key_name: proto_op.key_name,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            step: derivation_step_to_i32(op.step),
+            key_name: op.key_name,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_key_derivation::DerivationStep as DerivationStepProto;
+    use super::super::generated_ops::psa_key_derivation_input_key::Operation as OperationProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_key_derivation::DerivationStep;
+    use crate::operations::psa_key_derivation_input_key::Operation;
+    use crate::operations::NativeOperation;
+    use crate::requests::{request::RequestBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn key_derivation_input_key_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        proto.step = DerivationStepProto::Secret.into();
+        proto.key_name = String::from("some key");
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.step, DerivationStep::Secret);
+        assert_eq!(op.key_name, "some key");
+    }
+
+    #[test]
+    fn key_derivation_input_key_op_to_proto() {
+        let op = Operation {
+            step: DerivationStep::Secret,
+            key_name: String::from("some key"),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.step, DerivationStepProto::Secret as i32);
+        assert_eq!(proto.key_name, "some key");
+    }
+
+    #[test]
+    fn op_key_derivation_input_key_e2e() {
+        let op = Operation {
+            step: DerivationStep::Secret,
+            key_name: String::from("some key"),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaKeyDerivationInputKey(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaKeyDerivationInputKey)
+            .is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaKeyDerivationInputKey)
+            .is_err());
+    }
+}
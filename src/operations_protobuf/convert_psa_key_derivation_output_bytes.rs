@@ -0,0 +1,161 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_key_derivation_output_bytes::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_key_derivation_output_bytes::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Operation {
+            size: proto_op.size.try_into().or_else(|e| {
+                error!(
+                    "size field of psa_key_derivation_output_bytes::Operation can not be represented by an usize ({}).",
+                    e
+                );
+                Err(ResponseStatus::InvalidEncoding)
+            })?,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            size: op.size.try_into().or_else(|e| {
+                error!(
+                    "size field of psa_key_derivation_output_bytes::Operation can not be represented by an u64 ({}).",
+                    e
+                );
+                Err(ResponseStatus::InvalidEncoding)
+            })?,
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            data: proto_result.data.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            data: result.data.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_key_derivation_output_bytes::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_key_derivation_output_bytes::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn key_derivation_output_bytes_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        proto.size = 32;
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.size, 32);
+    }
+
+    #[test]
+    fn key_derivation_output_bytes_op_to_proto() {
+        let proto: OperationProto = Operation { size: 32 }
+            .try_into()
+            .expect("Failed to convert");
+
+        assert_eq!(proto.size, 32);
+    }
+
+    #[test]
+    fn key_derivation_output_bytes_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        proto.data = vec![0x11, 0x22, 0x33];
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(*result.data, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn key_derivation_output_bytes_resp_to_proto() {
+        let result = Result {
+            data: vec![0x11, 0x22, 0x33].into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.data, vec![0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn op_key_derivation_output_bytes_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaKeyDerivationOutputBytes(Operation {
+                size: 32,
+            }))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaKeyDerivationOutputBytes)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_key_derivation_output_bytes_e2e() {
+        let result = Result {
+            data: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaKeyDerivationOutputBytes(result))
+            .expect("Failed to convert response");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaKeyDerivationOutputBytes)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaKeyDerivationOutputBytes)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaKeyDerivationOutputBytes)
+            .is_err());
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_mac_compute::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_mac_compute::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use zeroize::Zeroizing;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let input = Zeroizing::new(proto_op.input);
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("alg field of psa_mac_compute::Operation message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            input,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        let alg = Some(op.alg.try_into()?);
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg,
+            input: op.input.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            mac: proto_result.mac.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            mac: result.mac.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm as algorithm_proto;
+    use super::super::generated_ops::psa_mac_compute::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{FullLengthMac, Hash, Mac};
+    use crate::operations::psa_mac_compute::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn mac_compute_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let input = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        proto.input = input.clone();
+        proto.key_name = key_name.clone();
+        proto.alg = Some(algorithm_proto::algorithm::Mac {
+            variant: Some(algorithm_proto::algorithm::mac::Variant::FullLength(
+                algorithm_proto::algorithm::mac::FullLength {
+                    variant: Some(algorithm_proto::algorithm::mac::full_length::Variant::Cmac(
+                        algorithm_proto::algorithm::mac::full_length::Cmac {},
+                    )),
+                },
+            )),
+        });
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.input, input.into());
+        assert_eq!(op.key_name, key_name);
+        assert_eq!(op.alg, Mac::FullLength(FullLengthMac::Cmac));
+    }
+
+    #[test]
+    fn mac_compute_proto_with_no_alg() {
+        let mut proto: OperationProto = Default::default();
+        proto.key_name = "test name".to_string();
+
+        let status = TryInto::<Operation>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn mac_compute_op_to_proto() {
+        let input = vec![0x11, 0x22, 0x33];
+        let key_name = "test name".to_string();
+        let op = Operation {
+            key_name: key_name.clone(),
+            alg: Mac::FullLength(FullLengthMac::Hmac {
+                hash_alg: Hash::Sha256,
+            }),
+            input: input.clone().into(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.input, input);
+        assert_eq!(proto.key_name, key_name);
+    }
+
+    #[test]
+    fn mac_compute_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        let mac = vec![0x11, 0x22, 0x33];
+        proto.mac = mac.clone();
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.mac, mac.into());
+    }
+
+    #[test]
+    fn mac_compute_resp_to_proto() {
+        let mac = vec![0x11, 0x22, 0x33];
+        let result = Result {
+            mac: mac.clone().into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.mac, mac);
+    }
+
+    #[test]
+    fn op_mac_compute_e2e() {
+        let op = Operation {
+            key_name: "test name".to_string(),
+            alg: Mac::FullLength(FullLengthMac::Hmac {
+                hash_alg: Hash::Sha256,
+            }),
+            input: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaMacCompute(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaMacCompute)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_mac_compute_e2e() {
+        let result = Result {
+            mac: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaMacCompute(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaMacCompute)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaMacCompute)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaMacCompute)
+            .is_err());
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::generated_ops::psa_mac_verify::{Operation as OperationProto, Result as ResultProto};
+use crate::operations::psa_mac_verify::{Operation, Result};
+use crate::requests::ResponseStatus;
+use log::error;
+use std::convert::{TryFrom, TryInto};
+use zeroize::Zeroizing;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let input = Zeroizing::new(proto_op.input);
+        let mac = Zeroizing::new(proto_op.mac);
+        Ok(Operation {
+            key_name: proto_op.key_name,
+            alg: proto_op
+                .alg
+                .ok_or_else(|| {
+                    error!("alg field of psa_mac_verify::Operation message is empty.");
+                    ResponseStatus::InvalidEncoding
+                })?
+                .try_into()?,
+            input,
+            mac,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        let alg = Some(op.alg.try_into()?);
+        Ok(OperationProto {
+            key_name: op.key_name,
+            alg,
+            input: op.input.to_vec(),
+            mac: op.mac.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(_proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {})
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(_result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {})
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_mac_verify::Operation as OperationProto;
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::{FullLengthMac, Hash, Mac};
+    use crate::operations::psa_mac_verify::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, Opcode};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    fn get_op() -> Operation {
+        Operation {
+            key_name: "test name".to_string(),
+            alg: Mac::FullLength(FullLengthMac::Hmac {
+                hash_alg: Hash::Sha256,
+            }),
+            input: vec![0x11, 0x22, 0x33].into(),
+            mac: vec![0xa5; 32].into(),
+        }
+    }
+
+    #[test]
+    fn mac_verify_op_to_proto_to_op() {
+        let proto: OperationProto = get_op().try_into().expect("Failed to convert");
+
+        assert_eq!(proto.key_name, "test name");
+        assert_eq!(proto.input, vec![0x11, 0x22, 0x33]);
+        assert_eq!(proto.mac, vec![0xa5; 32]);
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.key_name, "test name");
+        assert_eq!(
+            op.alg,
+            Mac::FullLength(FullLengthMac::Hmac {
+                hash_alg: Hash::Sha256
+            })
+        );
+        assert_eq!(op.input, vec![0x11, 0x22, 0x33].into());
+        assert_eq!(op.mac, vec![0xa5; 32].into());
+    }
+
+    #[test]
+    fn op_mac_verify_e2e() {
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaMacVerify(get_op()))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaMacVerify)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_mac_verify_e2e() {
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaMacVerify(Result {}))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER.body_to_result(body, Opcode::PsaMacVerify).is_ok());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaMacVerify)
+            .is_err());
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::convert_psa_algorithm::raw_key_agreement_to_i32;
+use super::generated_ops::psa_algorithm::algorithm::key_agreement::Raw as RawKeyAgreementProto;
+use super::generated_ops::psa_raw_key_agreement::{
+    Operation as OperationProto, Result as ResultProto,
+};
+use crate::operations::psa_raw_key_agreement::{Operation, Result};
+use crate::requests::ResponseStatus;
+use std::convert::{TryFrom, TryInto};
+use zeroize::Zeroizing;
+
+impl TryFrom<OperationProto> for Operation {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_op: OperationProto) -> std::result::Result<Self, Self::Error> {
+        let peer_key = Zeroizing::new(proto_op.peer_key);
+        Ok(Operation {
+            alg: RawKeyAgreementProto::try_from(proto_op.alg)?.try_into()?,
+            private_key_name: proto_op.private_key_name,
+            peer_key,
+        })
+    }
+}
+
+impl TryFrom<Operation> for OperationProto {
+    type Error = ResponseStatus;
+
+    fn try_from(op: Operation) -> std::result::Result<Self, Self::Error> {
+        Ok(OperationProto {
+            alg: raw_key_agreement_to_i32(op.alg),
+            private_key_name: op.private_key_name,
+            peer_key: op.peer_key.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<ResultProto> for Result {
+    type Error = ResponseStatus;
+
+    fn try_from(proto_result: ResultProto) -> std::result::Result<Self, Self::Error> {
+        Ok(Result {
+            shared_secret: proto_result.shared_secret.into(),
+        })
+    }
+}
+
+impl TryFrom<Result> for ResultProto {
+    type Error = ResponseStatus;
+
+    fn try_from(result: Result) -> std::result::Result<Self, Self::Error> {
+        Ok(ResultProto {
+            shared_secret: result.shared_secret.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::generated_ops::psa_algorithm::algorithm::key_agreement::Raw as RawKeyAgreementProto;
+    use super::super::generated_ops::psa_raw_key_agreement::{
+        Operation as OperationProto, Result as ResultProto,
+    };
+    use super::super::{Convert, ProtobufConverter};
+    use crate::operations::psa_algorithm::RawKeyAgreement;
+    use crate::operations::psa_raw_key_agreement::{Operation, Result};
+    use crate::operations::{NativeOperation, NativeResult};
+    use crate::requests::{request::RequestBody, response::ResponseBody, Opcode, ResponseStatus};
+    use std::convert::TryInto;
+
+    static CONVERTER: ProtobufConverter = ProtobufConverter {};
+
+    #[test]
+    fn raw_key_agreement_proto_to_op() {
+        let mut proto: OperationProto = Default::default();
+        let peer_key = vec![0x04; 65];
+        let private_key_name = "test name".to_string();
+        proto.peer_key = peer_key.clone();
+        proto.private_key_name = private_key_name.clone();
+        proto.alg = RawKeyAgreementProto::Ecdh.into();
+
+        let op: Operation = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(op.peer_key, peer_key.into());
+        assert_eq!(op.private_key_name, private_key_name);
+        assert_eq!(op.alg, RawKeyAgreement::Ecdh);
+    }
+
+    #[test]
+    fn raw_key_agreement_proto_with_no_alg() {
+        let mut proto: OperationProto = Default::default();
+        proto.private_key_name = "test name".to_string();
+
+        let status = TryInto::<Operation>::try_into(proto).unwrap_err();
+
+        assert_eq!(status, ResponseStatus::InvalidEncoding);
+    }
+
+    #[test]
+    fn raw_key_agreement_op_to_proto() {
+        let peer_key = vec![0x04; 65];
+        let private_key_name = "test name".to_string();
+        let op = Operation {
+            alg: RawKeyAgreement::Ffdh,
+            private_key_name: private_key_name.clone(),
+            peer_key: peer_key.clone().into(),
+        };
+
+        let proto: OperationProto = op.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.peer_key, peer_key);
+        assert_eq!(proto.private_key_name, private_key_name);
+        assert_eq!(proto.alg, RawKeyAgreementProto::Ffdh as i32);
+    }
+
+    #[test]
+    fn raw_key_agreement_proto_to_resp() {
+        let mut proto: ResultProto = Default::default();
+        let shared_secret = vec![0x11, 0x22, 0x33];
+        proto.shared_secret = shared_secret.clone();
+
+        let result: Result = proto.try_into().expect("Failed to convert");
+
+        assert_eq!(result.shared_secret, shared_secret.into());
+    }
+
+    #[test]
+    fn raw_key_agreement_resp_to_proto() {
+        let shared_secret = vec![0x11, 0x22, 0x33];
+        let result = Result {
+            shared_secret: shared_secret.clone().into(),
+        };
+
+        let proto: ResultProto = result.try_into().expect("Failed to convert");
+
+        assert_eq!(proto.shared_secret, shared_secret);
+    }
+
+    #[test]
+    fn op_raw_key_agreement_e2e() {
+        let op = Operation {
+            alg: RawKeyAgreement::Ecdh,
+            private_key_name: "test name".to_string(),
+            peer_key: vec![0x04; 65].into(),
+        };
+        let body = CONVERTER
+            .operation_to_body(NativeOperation::PsaRawKeyAgreement(op))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_operation(body, Opcode::PsaRawKeyAgreement)
+            .is_ok());
+    }
+
+    #[test]
+    fn resp_raw_key_agreement_e2e() {
+        let result = Result {
+            shared_secret: vec![0x11, 0x22, 0x33].into(),
+        };
+        let body = CONVERTER
+            .result_to_body(NativeResult::PsaRawKeyAgreement(result))
+            .expect("Failed to convert request");
+
+        assert!(CONVERTER
+            .body_to_result(body, Opcode::PsaRawKeyAgreement)
+            .is_ok());
+    }
+
+    #[test]
+    fn result_from_mangled_resp_body() {
+        let resp_body =
+            ResponseBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+        assert!(CONVERTER
+            .body_to_result(resp_body, Opcode::PsaRawKeyAgreement)
+            .is_err());
+    }
+
+    #[test]
+    fn op_from_mangled_req_body() {
+        let req_body =
+            RequestBody::from_bytes(vec![0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+
+        assert!(CONVERTER
+            .body_to_operation(req_body, Opcode::PsaRawKeyAgreement)
+            .is_err());
+    }
+}
@@ -24,6 +24,53 @@ include_protobuf_as_module!(psa_import_key);
 include_protobuf_as_module!(list_opcodes);
 include_protobuf_as_module!(list_providers);
 include_protobuf_as_module!(ping);
+include_protobuf_as_module!(psa_hash_compute);
+include_protobuf_as_module!(psa_hash_compare);
+include_protobuf_as_module!(psa_mac_compute);
+include_protobuf_as_module!(psa_mac_verify);
+include_protobuf_as_module!(psa_cipher_encrypt);
+include_protobuf_as_module!(psa_cipher_decrypt);
+include_protobuf_as_module!(psa_aead_encrypt);
+include_protobuf_as_module!(psa_aead_decrypt);
+include_protobuf_as_module!(psa_asymmetric_encrypt);
+include_protobuf_as_module!(psa_asymmetric_decrypt);
+include_protobuf_as_module!(psa_raw_key_agreement);
+include_protobuf_as_module!(psa_generate_random);
+include_protobuf_as_module!(psa_export_key);
+include_protobuf_as_module!(psa_get_key_attributes);
+include_protobuf_as_module!(list_keys);
+include_protobuf_as_module!(list_authenticators);
+include_protobuf_as_module!(list_clients);
+include_protobuf_as_module!(delete_client);
+include_protobuf_as_module!(psa_copy_key);
+include_protobuf_as_module!(psa_sign_message);
+include_protobuf_as_module!(psa_verify_message);
+include_protobuf_as_module!(psa_key_derivation);
+include_protobuf_as_module!(psa_key_derivation_setup);
+include_protobuf_as_module!(psa_key_derivation_input_bytes);
+include_protobuf_as_module!(psa_key_derivation_input_key);
+include_protobuf_as_module!(psa_key_derivation_output_bytes);
+include_protobuf_as_module!(psa_key_derivation_output_key);
+include_protobuf_as_module!(psa_hash_setup);
+include_protobuf_as_module!(psa_hash_update);
+include_protobuf_as_module!(psa_hash_finish);
+include_protobuf_as_module!(psa_hash_abort);
+include_protobuf_as_module!(psa_cipher_encrypt_setup);
+include_protobuf_as_module!(psa_cipher_decrypt_setup);
+include_protobuf_as_module!(psa_cipher_update);
+include_protobuf_as_module!(psa_cipher_finish);
+include_protobuf_as_module!(psa_cipher_abort);
+include_protobuf_as_module!(psa_aead_encrypt_setup);
+include_protobuf_as_module!(psa_aead_decrypt_setup);
+include_protobuf_as_module!(psa_aead_update);
+include_protobuf_as_module!(psa_aead_finish);
+include_protobuf_as_module!(psa_aead_verify);
+include_protobuf_as_module!(psa_aead_abort);
+include_protobuf_as_module!(can_do_crypto);
+include_protobuf_as_module!(key_attestation);
+include_protobuf_as_module!(prepare_key_attestation);
+include_protobuf_as_module!(attest_key);
+include_protobuf_as_module!(create_certificate_signing_request);
 include_protobuf_as_module!(psa_key_attributes);
 include_protobuf_as_module!(psa_algorithm);
 
@@ -31,6 +78,7 @@ use crate::requests::{ResponseStatus, Result};
 use log::error;
 use psa_algorithm::algorithm::{aead::AeadWithDefaultLengthTag, key_agreement::Raw, Cipher, Hash};
 use psa_key_attributes::key_type::{DhFamily, EccFamily};
+use psa_key_derivation::DerivationStep;
 use std::convert::TryFrom;
 
 impl TryFrom<i32> for Cipher {
@@ -111,6 +159,19 @@ impl TryFrom<i32> for DhFamily {
     }
 }
 
+impl TryFrom<i32> for DerivationStep {
+    type Error = ResponseStatus;
+    fn try_from(step_val: i32) -> Result<Self> {
+        Ok(DerivationStep::from_i32(step_val).ok_or_else(|| {
+            error!(
+                "Value {} not recognised as a valid key derivation step encoding.",
+                step_val
+            );
+            ResponseStatus::InvalidEncoding
+        })?)
+    }
+}
+
 pub(super) trait ClearProtoMessage {
     fn clear_message(&mut self) {}
 }
@@ -137,6 +198,8 @@ empty_clear_message!(psa_generate_key::Result);
 empty_clear_message!(psa_export_public_key::Operation);
 empty_clear_message!(psa_import_key::Result);
 empty_clear_message!(psa_verify_hash::Result);
+empty_clear_message!(psa_hash_compare::Result);
+empty_clear_message!(psa_mac_verify::Result);
 
 impl ClearProtoMessage for psa_sign_hash::Operation {
     fn clear_message(&mut self) {
@@ -168,3 +231,325 @@ impl ClearProtoMessage for psa_export_public_key::Result {
         self.data.zeroize();
     }
 }
+
+impl ClearProtoMessage for psa_hash_compute::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_hash_compute::Result {
+    fn clear_message(&mut self) {
+        self.hash.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_hash_compare::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+        self.hash.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_mac_compute::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_mac_compute::Result {
+    fn clear_message(&mut self) {
+        self.mac.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_mac_verify::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+        self.mac.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_encrypt::Operation {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_encrypt::Result {
+    fn clear_message(&mut self) {
+        self.iv.zeroize();
+        self.ciphertext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_decrypt::Operation {
+    fn clear_message(&mut self) {
+        self.iv.zeroize();
+        self.ciphertext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_decrypt::Result {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_encrypt::Operation {
+    fn clear_message(&mut self) {
+        self.nonce.zeroize();
+        self.additional_data.zeroize();
+        self.plaintext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_encrypt::Result {
+    fn clear_message(&mut self) {
+        self.ciphertext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_decrypt::Operation {
+    fn clear_message(&mut self) {
+        self.nonce.zeroize();
+        self.additional_data.zeroize();
+        self.ciphertext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_decrypt::Result {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_asymmetric_encrypt::Operation {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+        self.salt.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_asymmetric_encrypt::Result {
+    fn clear_message(&mut self) {
+        self.ciphertext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_asymmetric_decrypt::Operation {
+    fn clear_message(&mut self) {
+        self.ciphertext.zeroize();
+        self.salt.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_asymmetric_decrypt::Result {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_raw_key_agreement::Operation {
+    fn clear_message(&mut self) {
+        self.peer_key.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_raw_key_agreement::Result {
+    fn clear_message(&mut self) {
+        self.shared_secret.zeroize();
+    }
+}
+
+empty_clear_message!(psa_generate_random::Operation);
+
+impl ClearProtoMessage for psa_generate_random::Result {
+    fn clear_message(&mut self) {
+        self.random_bytes.zeroize();
+    }
+}
+
+empty_clear_message!(psa_export_key::Operation);
+
+impl ClearProtoMessage for psa_export_key::Result {
+    fn clear_message(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+empty_clear_message!(psa_get_key_attributes::Operation);
+empty_clear_message!(psa_get_key_attributes::Result);
+
+empty_clear_message!(list_keys::Operation);
+empty_clear_message!(list_keys::Result);
+
+empty_clear_message!(list_authenticators::Operation);
+empty_clear_message!(list_authenticators::Result);
+
+empty_clear_message!(list_clients::Operation);
+empty_clear_message!(list_clients::Result);
+empty_clear_message!(delete_client::Operation);
+empty_clear_message!(delete_client::Result);
+
+empty_clear_message!(psa_copy_key::Operation);
+empty_clear_message!(psa_copy_key::Result);
+
+impl ClearProtoMessage for psa_sign_message::Operation {
+    fn clear_message(&mut self) {
+        self.message.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_sign_message::Result {
+    fn clear_message(&mut self) {
+        self.signature.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_verify_message::Operation {
+    fn clear_message(&mut self) {
+        self.message.zeroize();
+        self.signature.zeroize();
+    }
+}
+
+empty_clear_message!(psa_verify_message::Result);
+
+empty_clear_message!(psa_key_derivation_setup::Operation);
+empty_clear_message!(psa_key_derivation_setup::Result);
+empty_clear_message!(psa_key_derivation_input_bytes::Result);
+empty_clear_message!(psa_key_derivation_input_key::Operation);
+empty_clear_message!(psa_key_derivation_input_key::Result);
+empty_clear_message!(psa_key_derivation_output_bytes::Operation);
+empty_clear_message!(psa_key_derivation_output_key::Operation);
+empty_clear_message!(psa_key_derivation_output_key::Result);
+
+impl ClearProtoMessage for psa_key_derivation_input_bytes::Operation {
+    fn clear_message(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_key_derivation_output_bytes::Result {
+    fn clear_message(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+empty_clear_message!(psa_hash_setup::Operation);
+empty_clear_message!(psa_hash_setup::Result);
+empty_clear_message!(psa_hash_update::Result);
+empty_clear_message!(psa_hash_finish::Operation);
+empty_clear_message!(psa_hash_abort::Operation);
+empty_clear_message!(psa_hash_abort::Result);
+
+impl ClearProtoMessage for psa_hash_update::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_hash_finish::Result {
+    fn clear_message(&mut self) {
+        self.hash.zeroize();
+    }
+}
+
+empty_clear_message!(psa_cipher_encrypt_setup::Operation);
+empty_clear_message!(psa_cipher_decrypt_setup::Result);
+empty_clear_message!(psa_cipher_finish::Operation);
+empty_clear_message!(psa_cipher_abort::Operation);
+empty_clear_message!(psa_cipher_abort::Result);
+
+impl ClearProtoMessage for psa_cipher_encrypt_setup::Result {
+    fn clear_message(&mut self) {
+        self.iv.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_decrypt_setup::Operation {
+    fn clear_message(&mut self) {
+        self.iv.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_update::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_update::Result {
+    fn clear_message(&mut self) {
+        self.output.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_cipher_finish::Result {
+    fn clear_message(&mut self) {
+        self.output.zeroize();
+    }
+}
+
+empty_clear_message!(psa_aead_encrypt_setup::Result);
+empty_clear_message!(psa_aead_decrypt_setup::Result);
+empty_clear_message!(psa_aead_finish::Operation);
+empty_clear_message!(psa_aead_abort::Operation);
+empty_clear_message!(psa_aead_abort::Result);
+
+impl ClearProtoMessage for psa_aead_encrypt_setup::Operation {
+    fn clear_message(&mut self) {
+        self.nonce.zeroize();
+        self.additional_data.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_decrypt_setup::Operation {
+    fn clear_message(&mut self) {
+        self.nonce.zeroize();
+        self.additional_data.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_update::Operation {
+    fn clear_message(&mut self) {
+        self.input.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_update::Result {
+    fn clear_message(&mut self) {
+        self.output.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_finish::Result {
+    fn clear_message(&mut self) {
+        self.ciphertext.zeroize();
+        self.tag.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_verify::Operation {
+    fn clear_message(&mut self) {
+        self.tag.zeroize();
+    }
+}
+
+impl ClearProtoMessage for psa_aead_verify::Result {
+    fn clear_message(&mut self) {
+        self.plaintext.zeroize();
+    }
+}
+
+empty_clear_message!(can_do_crypto::Operation);
+empty_clear_message!(can_do_crypto::Result);
+
+empty_clear_message!(prepare_key_attestation::Operation);
+empty_clear_message!(prepare_key_attestation::Result);
+empty_clear_message!(attest_key::Operation);
+empty_clear_message!(attest_key::Result);
+
+empty_clear_message!(create_certificate_signing_request::Operation);
+empty_clear_message!(create_certificate_signing_request::Result);
@@ -14,6 +14,53 @@ mod convert_psa_sign_hash;
 mod convert_psa_verify_hash;
 mod convert_list_providers;
 mod convert_list_opcodes;
+mod convert_psa_hash_compute;
+mod convert_psa_hash_compare;
+mod convert_psa_mac_compute;
+mod convert_psa_mac_verify;
+mod convert_psa_cipher_encrypt;
+mod convert_psa_cipher_decrypt;
+mod convert_psa_aead_encrypt;
+mod convert_psa_aead_decrypt;
+mod convert_psa_asymmetric_encrypt;
+mod convert_psa_asymmetric_decrypt;
+mod convert_psa_raw_key_agreement;
+mod convert_psa_generate_random;
+mod convert_psa_export_key;
+mod convert_psa_get_key_attributes;
+mod convert_list_keys;
+mod convert_list_authenticators;
+mod convert_list_clients;
+mod convert_delete_client;
+mod convert_psa_copy_key;
+mod convert_psa_sign_message;
+mod convert_psa_verify_message;
+mod convert_psa_key_derivation;
+mod convert_psa_key_derivation_setup;
+mod convert_psa_key_derivation_input_bytes;
+mod convert_psa_key_derivation_input_key;
+mod convert_psa_key_derivation_output_bytes;
+mod convert_psa_key_derivation_output_key;
+mod convert_psa_hash_setup;
+mod convert_psa_hash_update;
+mod convert_psa_hash_finish;
+mod convert_psa_hash_abort;
+mod convert_psa_cipher_encrypt_setup;
+mod convert_psa_cipher_decrypt_setup;
+mod convert_psa_cipher_update;
+mod convert_psa_cipher_finish;
+mod convert_psa_cipher_abort;
+mod convert_psa_aead_encrypt_setup;
+mod convert_psa_aead_decrypt_setup;
+mod convert_psa_aead_update;
+mod convert_psa_aead_finish;
+mod convert_psa_aead_verify;
+mod convert_psa_aead_abort;
+mod convert_can_do_crypto;
+mod convert_key_attestation;
+mod convert_prepare_key_attestation;
+mod convert_attest_key;
+mod convert_create_certificate_signing_request;
 
 #[rustfmt::skip]
 #[allow(unused_qualifications, missing_copy_implementations, clippy::pedantic, clippy::module_inception)]
@@ -23,15 +70,60 @@ use crate::operations::{Convert, NativeOperation, NativeResult};
 use crate::requests::{
     request::RequestBody, response::ResponseBody, BodyType, Opcode, ResponseStatus, Result,
 };
+use generated_ops::attest_key as attest_key_proto;
+use generated_ops::can_do_crypto as can_do_crypto_proto;
+use generated_ops::create_certificate_signing_request as create_certificate_signing_request_proto;
+use generated_ops::delete_client as delete_client_proto;
+use generated_ops::list_authenticators as list_authenticators_proto;
+use generated_ops::list_clients as list_clients_proto;
+use generated_ops::list_keys as list_keys_proto;
 use generated_ops::list_opcodes as list_opcodes_proto;
 use generated_ops::list_providers as list_providers_proto;
 use generated_ops::ping as ping_proto;
+use generated_ops::prepare_key_attestation as prepare_key_attestation_proto;
+use generated_ops::psa_aead_abort as psa_aead_abort_proto;
+use generated_ops::psa_aead_decrypt as psa_aead_decrypt_proto;
+use generated_ops::psa_aead_decrypt_setup as psa_aead_decrypt_setup_proto;
+use generated_ops::psa_aead_encrypt as psa_aead_encrypt_proto;
+use generated_ops::psa_aead_encrypt_setup as psa_aead_encrypt_setup_proto;
+use generated_ops::psa_aead_finish as psa_aead_finish_proto;
+use generated_ops::psa_aead_update as psa_aead_update_proto;
+use generated_ops::psa_aead_verify as psa_aead_verify_proto;
+use generated_ops::psa_asymmetric_decrypt as psa_asymmetric_decrypt_proto;
+use generated_ops::psa_asymmetric_encrypt as psa_asymmetric_encrypt_proto;
+use generated_ops::psa_cipher_abort as psa_cipher_abort_proto;
+use generated_ops::psa_cipher_decrypt as psa_cipher_decrypt_proto;
+use generated_ops::psa_cipher_decrypt_setup as psa_cipher_decrypt_setup_proto;
+use generated_ops::psa_cipher_encrypt as psa_cipher_encrypt_proto;
+use generated_ops::psa_cipher_encrypt_setup as psa_cipher_encrypt_setup_proto;
+use generated_ops::psa_cipher_finish as psa_cipher_finish_proto;
+use generated_ops::psa_cipher_update as psa_cipher_update_proto;
+use generated_ops::psa_copy_key as psa_copy_key_proto;
 use generated_ops::psa_destroy_key as psa_destroy_key_proto;
+use generated_ops::psa_export_key as psa_export_key_proto;
 use generated_ops::psa_export_public_key as psa_export_public_key_proto;
 use generated_ops::psa_generate_key as psa_generate_key_proto;
+use generated_ops::psa_generate_random as psa_generate_random_proto;
+use generated_ops::psa_get_key_attributes as psa_get_key_attributes_proto;
+use generated_ops::psa_hash_abort as psa_hash_abort_proto;
+use generated_ops::psa_hash_compare as psa_hash_compare_proto;
+use generated_ops::psa_hash_compute as psa_hash_compute_proto;
+use generated_ops::psa_hash_finish as psa_hash_finish_proto;
+use generated_ops::psa_hash_setup as psa_hash_setup_proto;
+use generated_ops::psa_hash_update as psa_hash_update_proto;
 use generated_ops::psa_import_key as psa_import_key_proto;
+use generated_ops::psa_key_derivation_input_bytes as psa_key_derivation_input_bytes_proto;
+use generated_ops::psa_key_derivation_input_key as psa_key_derivation_input_key_proto;
+use generated_ops::psa_key_derivation_output_bytes as psa_key_derivation_output_bytes_proto;
+use generated_ops::psa_key_derivation_output_key as psa_key_derivation_output_key_proto;
+use generated_ops::psa_key_derivation_setup as psa_key_derivation_setup_proto;
+use generated_ops::psa_mac_compute as psa_mac_compute_proto;
+use generated_ops::psa_mac_verify as psa_mac_verify_proto;
+use generated_ops::psa_raw_key_agreement as psa_raw_key_agreement_proto;
 use generated_ops::psa_sign_hash as psa_sign_hash_proto;
+use generated_ops::psa_sign_message as psa_sign_message_proto;
 use generated_ops::psa_verify_hash as psa_verify_hash_proto;
+use generated_ops::psa_verify_message as psa_verify_message_proto;
 use generated_ops::ClearProtoMessage;
 use prost::Message;
 use std::convert::TryInto;
@@ -107,6 +199,181 @@ impl Convert for ProtobufConverter {
                 body.bytes(),
                 psa_verify_hash_proto::Operation
             ))),
+            Opcode::PsaHashCompute => Ok(NativeOperation::PsaHashCompute(wire_to_native!(
+                body.bytes(),
+                psa_hash_compute_proto::Operation
+            ))),
+            Opcode::PsaHashCompare => Ok(NativeOperation::PsaHashCompare(wire_to_native!(
+                body.bytes(),
+                psa_hash_compare_proto::Operation
+            ))),
+            Opcode::PsaMacCompute => Ok(NativeOperation::PsaMacCompute(wire_to_native!(
+                body.bytes(),
+                psa_mac_compute_proto::Operation
+            ))),
+            Opcode::PsaMacVerify => Ok(NativeOperation::PsaMacVerify(wire_to_native!(
+                body.bytes(),
+                psa_mac_verify_proto::Operation
+            ))),
+            Opcode::PsaCipherEncrypt => Ok(NativeOperation::PsaCipherEncrypt(wire_to_native!(
+                body.bytes(),
+                psa_cipher_encrypt_proto::Operation
+            ))),
+            Opcode::PsaCipherDecrypt => Ok(NativeOperation::PsaCipherDecrypt(wire_to_native!(
+                body.bytes(),
+                psa_cipher_decrypt_proto::Operation
+            ))),
+            Opcode::PsaAeadEncrypt => Ok(NativeOperation::PsaAeadEncrypt(wire_to_native!(
+                body.bytes(),
+                psa_aead_encrypt_proto::Operation
+            ))),
+            Opcode::PsaAeadDecrypt => Ok(NativeOperation::PsaAeadDecrypt(wire_to_native!(
+                body.bytes(),
+                psa_aead_decrypt_proto::Operation
+            ))),
+            Opcode::PsaAsymmetricEncrypt => Ok(NativeOperation::PsaAsymmetricEncrypt(
+                wire_to_native!(body.bytes(), psa_asymmetric_encrypt_proto::Operation),
+            )),
+            Opcode::PsaAsymmetricDecrypt => Ok(NativeOperation::PsaAsymmetricDecrypt(
+                wire_to_native!(body.bytes(), psa_asymmetric_decrypt_proto::Operation),
+            )),
+            Opcode::PsaRawKeyAgreement => Ok(NativeOperation::PsaRawKeyAgreement(wire_to_native!(
+                body.bytes(),
+                psa_raw_key_agreement_proto::Operation
+            ))),
+            Opcode::PsaGenerateRandom => Ok(NativeOperation::PsaGenerateRandom(wire_to_native!(
+                body.bytes(),
+                psa_generate_random_proto::Operation
+            ))),
+            Opcode::PsaExportKey => Ok(NativeOperation::PsaExportKey(wire_to_native!(
+                body.bytes(),
+                psa_export_key_proto::Operation
+            ))),
+            Opcode::PsaGetKeyAttributes => Ok(NativeOperation::PsaGetKeyAttributes(
+                wire_to_native!(body.bytes(), psa_get_key_attributes_proto::Operation),
+            )),
+            Opcode::ListKeys => Ok(NativeOperation::ListKeys(wire_to_native!(
+                body.bytes(),
+                list_keys_proto::Operation
+            ))),
+            Opcode::ListAuthenticators => Ok(NativeOperation::ListAuthenticators(wire_to_native!(
+                body.bytes(),
+                list_authenticators_proto::Operation
+            ))),
+            Opcode::ListClients => Ok(NativeOperation::ListClients(wire_to_native!(
+                body.bytes(),
+                list_clients_proto::Operation
+            ))),
+            Opcode::DeleteClient => Ok(NativeOperation::DeleteClient(wire_to_native!(
+                body.bytes(),
+                delete_client_proto::Operation
+            ))),
+            Opcode::PsaCopyKey => Ok(NativeOperation::PsaCopyKey(wire_to_native!(
+                body.bytes(),
+                psa_copy_key_proto::Operation
+            ))),
+            Opcode::PsaSignMessage => Ok(NativeOperation::PsaSignMessage(wire_to_native!(
+                body.bytes(),
+                psa_sign_message_proto::Operation
+            ))),
+            Opcode::PsaVerifyMessage => Ok(NativeOperation::PsaVerifyMessage(wire_to_native!(
+                body.bytes(),
+                psa_verify_message_proto::Operation
+            ))),
+            Opcode::PsaKeyDerivationSetup => Ok(NativeOperation::PsaKeyDerivationSetup(
+                wire_to_native!(body.bytes(), psa_key_derivation_setup_proto::Operation),
+            )),
+            Opcode::PsaKeyDerivationInputBytes => Ok(NativeOperation::PsaKeyDerivationInputBytes(
+                wire_to_native!(
+                    body.bytes(),
+                    psa_key_derivation_input_bytes_proto::Operation
+                ),
+            )),
+            Opcode::PsaKeyDerivationInputKey => Ok(NativeOperation::PsaKeyDerivationInputKey(
+                wire_to_native!(body.bytes(), psa_key_derivation_input_key_proto::Operation),
+            )),
+            Opcode::PsaKeyDerivationOutputBytes => Ok(
+                NativeOperation::PsaKeyDerivationOutputBytes(wire_to_native!(
+                    body.bytes(),
+                    psa_key_derivation_output_bytes_proto::Operation
+                )),
+            ),
+            Opcode::PsaKeyDerivationOutputKey => Ok(NativeOperation::PsaKeyDerivationOutputKey(
+                wire_to_native!(body.bytes(), psa_key_derivation_output_key_proto::Operation),
+            )),
+            Opcode::PsaHashSetup => Ok(NativeOperation::PsaHashSetup(wire_to_native!(
+                body.bytes(),
+                psa_hash_setup_proto::Operation
+            ))),
+            Opcode::PsaHashUpdate => Ok(NativeOperation::PsaHashUpdate(wire_to_native!(
+                body.bytes(),
+                psa_hash_update_proto::Operation
+            ))),
+            Opcode::PsaHashFinish => Ok(NativeOperation::PsaHashFinish(wire_to_native!(
+                body.bytes(),
+                psa_hash_finish_proto::Operation
+            ))),
+            Opcode::PsaHashAbort => Ok(NativeOperation::PsaHashAbort(wire_to_native!(
+                body.bytes(),
+                psa_hash_abort_proto::Operation
+            ))),
+            Opcode::PsaCipherEncryptSetup => Ok(NativeOperation::PsaCipherEncryptSetup(
+                wire_to_native!(body.bytes(), psa_cipher_encrypt_setup_proto::Operation),
+            )),
+            Opcode::PsaCipherDecryptSetup => Ok(NativeOperation::PsaCipherDecryptSetup(
+                wire_to_native!(body.bytes(), psa_cipher_decrypt_setup_proto::Operation),
+            )),
+            Opcode::PsaCipherUpdate => Ok(NativeOperation::PsaCipherUpdate(wire_to_native!(
+                body.bytes(),
+                psa_cipher_update_proto::Operation
+            ))),
+            Opcode::PsaCipherFinish => Ok(NativeOperation::PsaCipherFinish(wire_to_native!(
+                body.bytes(),
+                psa_cipher_finish_proto::Operation
+            ))),
+            Opcode::PsaCipherAbort => Ok(NativeOperation::PsaCipherAbort(wire_to_native!(
+                body.bytes(),
+                psa_cipher_abort_proto::Operation
+            ))),
+            Opcode::PsaAeadEncryptSetup => Ok(NativeOperation::PsaAeadEncryptSetup(
+                wire_to_native!(body.bytes(), psa_aead_encrypt_setup_proto::Operation),
+            )),
+            Opcode::PsaAeadDecryptSetup => Ok(NativeOperation::PsaAeadDecryptSetup(
+                wire_to_native!(body.bytes(), psa_aead_decrypt_setup_proto::Operation),
+            )),
+            Opcode::PsaAeadUpdate => Ok(NativeOperation::PsaAeadUpdate(wire_to_native!(
+                body.bytes(),
+                psa_aead_update_proto::Operation
+            ))),
+            Opcode::PsaAeadFinish => Ok(NativeOperation::PsaAeadFinish(wire_to_native!(
+                body.bytes(),
+                psa_aead_finish_proto::Operation
+            ))),
+            Opcode::PsaAeadVerify => Ok(NativeOperation::PsaAeadVerify(wire_to_native!(
+                body.bytes(),
+                psa_aead_verify_proto::Operation
+            ))),
+            Opcode::PsaAeadAbort => Ok(NativeOperation::PsaAeadAbort(wire_to_native!(
+                body.bytes(),
+                psa_aead_abort_proto::Operation
+            ))),
+            Opcode::CanDoCrypto => Ok(NativeOperation::CanDoCrypto(wire_to_native!(
+                body.bytes(),
+                can_do_crypto_proto::Operation
+            ))),
+            Opcode::PrepareKeyAttestation => Ok(NativeOperation::PrepareKeyAttestation(
+                wire_to_native!(body.bytes(), prepare_key_attestation_proto::Operation),
+            )),
+            Opcode::AttestKey => Ok(NativeOperation::AttestKey(wire_to_native!(
+                body.bytes(),
+                attest_key_proto::Operation
+            ))),
+            Opcode::CreateCertificateSigningRequest => Ok(
+                NativeOperation::CreateCertificateSigningRequest(wire_to_native!(
+                    body.bytes(),
+                    create_certificate_signing_request_proto::Operation
+                )),
+            ),
         }
     }
 
@@ -140,6 +407,147 @@ impl Convert for ProtobufConverter {
             NativeOperation::PsaVerifyHash(operation) => Ok(RequestBody::from_bytes(
                 native_to_wire!(operation, psa_verify_hash_proto::Operation),
             )),
+            NativeOperation::PsaHashCompute(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_compute_proto::Operation),
+            )),
+            NativeOperation::PsaHashCompare(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_compare_proto::Operation),
+            )),
+            NativeOperation::PsaMacCompute(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_mac_compute_proto::Operation),
+            )),
+            NativeOperation::PsaMacVerify(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_mac_verify_proto::Operation),
+            )),
+            NativeOperation::PsaCipherEncrypt(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_encrypt_proto::Operation),
+            )),
+            NativeOperation::PsaCipherDecrypt(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_decrypt_proto::Operation),
+            )),
+            NativeOperation::PsaAeadEncrypt(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_encrypt_proto::Operation),
+            )),
+            NativeOperation::PsaAeadDecrypt(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_decrypt_proto::Operation),
+            )),
+            NativeOperation::PsaAsymmetricEncrypt(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_asymmetric_encrypt_proto::Operation),
+            )),
+            NativeOperation::PsaAsymmetricDecrypt(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_asymmetric_decrypt_proto::Operation),
+            )),
+            NativeOperation::PsaRawKeyAgreement(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_raw_key_agreement_proto::Operation),
+            )),
+            NativeOperation::PsaGenerateRandom(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_generate_random_proto::Operation),
+            )),
+            NativeOperation::PsaExportKey(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_export_key_proto::Operation),
+            )),
+            NativeOperation::PsaGetKeyAttributes(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_get_key_attributes_proto::Operation),
+            )),
+            NativeOperation::ListKeys(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
+                operation,
+                list_keys_proto::Operation
+            ))),
+            NativeOperation::ListAuthenticators(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, list_authenticators_proto::Operation),
+            )),
+            NativeOperation::ListClients(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, list_clients_proto::Operation),
+            )),
+            NativeOperation::DeleteClient(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, delete_client_proto::Operation),
+            )),
+            NativeOperation::PsaCopyKey(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
+                operation,
+                psa_copy_key_proto::Operation
+            ))),
+            NativeOperation::PsaSignMessage(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_sign_message_proto::Operation),
+            )),
+            NativeOperation::PsaVerifyMessage(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_verify_message_proto::Operation),
+            )),
+            NativeOperation::PsaKeyDerivationSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_key_derivation_setup_proto::Operation),
+            )),
+            NativeOperation::PsaKeyDerivationInputBytes(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_key_derivation_input_bytes_proto::Operation),
+            )),
+            NativeOperation::PsaKeyDerivationInputKey(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_key_derivation_input_key_proto::Operation),
+            )),
+            NativeOperation::PsaKeyDerivationOutputBytes(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_key_derivation_output_bytes_proto::Operation),
+            )),
+            NativeOperation::PsaKeyDerivationOutputKey(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_key_derivation_output_key_proto::Operation),
+            )),
+            NativeOperation::PsaHashSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_setup_proto::Operation),
+            )),
+            NativeOperation::PsaHashUpdate(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_update_proto::Operation),
+            )),
+            NativeOperation::PsaHashFinish(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_finish_proto::Operation),
+            )),
+            NativeOperation::PsaHashAbort(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_hash_abort_proto::Operation),
+            )),
+            NativeOperation::PsaCipherEncryptSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_encrypt_setup_proto::Operation),
+            )),
+            NativeOperation::PsaCipherDecryptSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_decrypt_setup_proto::Operation),
+            )),
+            NativeOperation::PsaCipherUpdate(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_update_proto::Operation),
+            )),
+            NativeOperation::PsaCipherFinish(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_finish_proto::Operation),
+            )),
+            NativeOperation::PsaCipherAbort(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_cipher_abort_proto::Operation),
+            )),
+            NativeOperation::PsaAeadEncryptSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_encrypt_setup_proto::Operation),
+            )),
+            NativeOperation::PsaAeadDecryptSetup(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_decrypt_setup_proto::Operation),
+            )),
+            NativeOperation::PsaAeadUpdate(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_update_proto::Operation),
+            )),
+            NativeOperation::PsaAeadFinish(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_finish_proto::Operation),
+            )),
+            NativeOperation::PsaAeadVerify(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_verify_proto::Operation),
+            )),
+            NativeOperation::PsaAeadAbort(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, psa_aead_abort_proto::Operation),
+            )),
+            NativeOperation::CanDoCrypto(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, can_do_crypto_proto::Operation),
+            )),
+            NativeOperation::PrepareKeyAttestation(operation) => Ok(RequestBody::from_bytes(
+                native_to_wire!(operation, prepare_key_attestation_proto::Operation),
+            )),
+            NativeOperation::AttestKey(operation) => Ok(RequestBody::from_bytes(native_to_wire!(
+                operation,
+                attest_key_proto::Operation
+            ))),
+            NativeOperation::CreateCertificateSigningRequest(operation) => {
+                Ok(RequestBody::from_bytes(native_to_wire!(
+                    operation,
+                    create_certificate_signing_request_proto::Operation
+                )))
+            }
         }
     }
 
@@ -181,6 +589,178 @@ impl Convert for ProtobufConverter {
                 body.bytes(),
                 psa_verify_hash_proto::Result
             ))),
+            Opcode::PsaHashCompute => Ok(NativeResult::PsaHashCompute(wire_to_native!(
+                body.bytes(),
+                psa_hash_compute_proto::Result
+            ))),
+            Opcode::PsaHashCompare => Ok(NativeResult::PsaHashCompare(wire_to_native!(
+                body.bytes(),
+                psa_hash_compare_proto::Result
+            ))),
+            Opcode::PsaMacCompute => Ok(NativeResult::PsaMacCompute(wire_to_native!(
+                body.bytes(),
+                psa_mac_compute_proto::Result
+            ))),
+            Opcode::PsaMacVerify => Ok(NativeResult::PsaMacVerify(wire_to_native!(
+                body.bytes(),
+                psa_mac_verify_proto::Result
+            ))),
+            Opcode::PsaCipherEncrypt => Ok(NativeResult::PsaCipherEncrypt(wire_to_native!(
+                body.bytes(),
+                psa_cipher_encrypt_proto::Result
+            ))),
+            Opcode::PsaCipherDecrypt => Ok(NativeResult::PsaCipherDecrypt(wire_to_native!(
+                body.bytes(),
+                psa_cipher_decrypt_proto::Result
+            ))),
+            Opcode::PsaAeadEncrypt => Ok(NativeResult::PsaAeadEncrypt(wire_to_native!(
+                body.bytes(),
+                psa_aead_encrypt_proto::Result
+            ))),
+            Opcode::PsaAeadDecrypt => Ok(NativeResult::PsaAeadDecrypt(wire_to_native!(
+                body.bytes(),
+                psa_aead_decrypt_proto::Result
+            ))),
+            Opcode::PsaAsymmetricEncrypt => Ok(NativeResult::PsaAsymmetricEncrypt(
+                wire_to_native!(body.bytes(), psa_asymmetric_encrypt_proto::Result),
+            )),
+            Opcode::PsaAsymmetricDecrypt => Ok(NativeResult::PsaAsymmetricDecrypt(
+                wire_to_native!(body.bytes(), psa_asymmetric_decrypt_proto::Result),
+            )),
+            Opcode::PsaRawKeyAgreement => Ok(NativeResult::PsaRawKeyAgreement(wire_to_native!(
+                body.bytes(),
+                psa_raw_key_agreement_proto::Result
+            ))),
+            Opcode::PsaGenerateRandom => Ok(NativeResult::PsaGenerateRandom(wire_to_native!(
+                body.bytes(),
+                psa_generate_random_proto::Result
+            ))),
+            Opcode::PsaExportKey => Ok(NativeResult::PsaExportKey(wire_to_native!(
+                body.bytes(),
+                psa_export_key_proto::Result
+            ))),
+            Opcode::PsaGetKeyAttributes => Ok(NativeResult::PsaGetKeyAttributes(wire_to_native!(
+                body.bytes(),
+                psa_get_key_attributes_proto::Result
+            ))),
+            Opcode::ListKeys => Ok(NativeResult::ListKeys(wire_to_native!(
+                body.bytes(),
+                list_keys_proto::Result
+            ))),
+            Opcode::ListAuthenticators => Ok(NativeResult::ListAuthenticators(wire_to_native!(
+                body.bytes(),
+                list_authenticators_proto::Result
+            ))),
+            Opcode::ListClients => Ok(NativeResult::ListClients(wire_to_native!(
+                body.bytes(),
+                list_clients_proto::Result
+            ))),
+            Opcode::DeleteClient => Ok(NativeResult::DeleteClient(wire_to_native!(
+                body.bytes(),
+                delete_client_proto::Result
+            ))),
+            Opcode::PsaCopyKey => Ok(NativeResult::PsaCopyKey(wire_to_native!(
+                body.bytes(),
+                psa_copy_key_proto::Result
+            ))),
+            Opcode::PsaSignMessage => Ok(NativeResult::PsaSignMessage(wire_to_native!(
+                body.bytes(),
+                psa_sign_message_proto::Result
+            ))),
+            Opcode::PsaVerifyMessage => Ok(NativeResult::PsaVerifyMessage(wire_to_native!(
+                body.bytes(),
+                psa_verify_message_proto::Result
+            ))),
+            Opcode::PsaKeyDerivationSetup => Ok(NativeResult::PsaKeyDerivationSetup(
+                wire_to_native!(body.bytes(), psa_key_derivation_setup_proto::Result),
+            )),
+            Opcode::PsaKeyDerivationInputBytes => Ok(NativeResult::PsaKeyDerivationInputBytes(
+                wire_to_native!(body.bytes(), psa_key_derivation_input_bytes_proto::Result),
+            )),
+            Opcode::PsaKeyDerivationInputKey => Ok(NativeResult::PsaKeyDerivationInputKey(
+                wire_to_native!(body.bytes(), psa_key_derivation_input_key_proto::Result),
+            )),
+            Opcode::PsaKeyDerivationOutputBytes => Ok(NativeResult::PsaKeyDerivationOutputBytes(
+                wire_to_native!(body.bytes(), psa_key_derivation_output_bytes_proto::Result),
+            )),
+            Opcode::PsaKeyDerivationOutputKey => Ok(NativeResult::PsaKeyDerivationOutputKey(
+                wire_to_native!(body.bytes(), psa_key_derivation_output_key_proto::Result),
+            )),
+            Opcode::PsaHashSetup => Ok(NativeResult::PsaHashSetup(wire_to_native!(
+                body.bytes(),
+                psa_hash_setup_proto::Result
+            ))),
+            Opcode::PsaHashUpdate => Ok(NativeResult::PsaHashUpdate(wire_to_native!(
+                body.bytes(),
+                psa_hash_update_proto::Result
+            ))),
+            Opcode::PsaHashFinish => Ok(NativeResult::PsaHashFinish(wire_to_native!(
+                body.bytes(),
+                psa_hash_finish_proto::Result
+            ))),
+            Opcode::PsaHashAbort => Ok(NativeResult::PsaHashAbort(wire_to_native!(
+                body.bytes(),
+                psa_hash_abort_proto::Result
+            ))),
+            Opcode::PsaCipherEncryptSetup => Ok(NativeResult::PsaCipherEncryptSetup(
+                wire_to_native!(body.bytes(), psa_cipher_encrypt_setup_proto::Result),
+            )),
+            Opcode::PsaCipherDecryptSetup => Ok(NativeResult::PsaCipherDecryptSetup(
+                wire_to_native!(body.bytes(), psa_cipher_decrypt_setup_proto::Result),
+            )),
+            Opcode::PsaCipherUpdate => Ok(NativeResult::PsaCipherUpdate(wire_to_native!(
+                body.bytes(),
+                psa_cipher_update_proto::Result
+            ))),
+            Opcode::PsaCipherFinish => Ok(NativeResult::PsaCipherFinish(wire_to_native!(
+                body.bytes(),
+                psa_cipher_finish_proto::Result
+            ))),
+            Opcode::PsaCipherAbort => Ok(NativeResult::PsaCipherAbort(wire_to_native!(
+                body.bytes(),
+                psa_cipher_abort_proto::Result
+            ))),
+            Opcode::PsaAeadEncryptSetup => Ok(NativeResult::PsaAeadEncryptSetup(wire_to_native!(
+                body.bytes(),
+                psa_aead_encrypt_setup_proto::Result
+            ))),
+            Opcode::PsaAeadDecryptSetup => Ok(NativeResult::PsaAeadDecryptSetup(wire_to_native!(
+                body.bytes(),
+                psa_aead_decrypt_setup_proto::Result
+            ))),
+            Opcode::PsaAeadUpdate => Ok(NativeResult::PsaAeadUpdate(wire_to_native!(
+                body.bytes(),
+                psa_aead_update_proto::Result
+            ))),
+            Opcode::PsaAeadFinish => Ok(NativeResult::PsaAeadFinish(wire_to_native!(
+                body.bytes(),
+                psa_aead_finish_proto::Result
+            ))),
+            Opcode::PsaAeadVerify => Ok(NativeResult::PsaAeadVerify(wire_to_native!(
+                body.bytes(),
+                psa_aead_verify_proto::Result
+            ))),
+            Opcode::PsaAeadAbort => Ok(NativeResult::PsaAeadAbort(wire_to_native!(
+                body.bytes(),
+                psa_aead_abort_proto::Result
+            ))),
+            Opcode::CanDoCrypto => Ok(NativeResult::CanDoCrypto(wire_to_native!(
+                body.bytes(),
+                can_do_crypto_proto::Result
+            ))),
+            Opcode::PrepareKeyAttestation => Ok(NativeResult::PrepareKeyAttestation(
+                wire_to_native!(body.bytes(), prepare_key_attestation_proto::Result),
+            )),
+            Opcode::AttestKey => Ok(NativeResult::AttestKey(wire_to_native!(
+                body.bytes(),
+                attest_key_proto::Result
+            ))),
+            Opcode::CreateCertificateSigningRequest => Ok(
+                NativeResult::CreateCertificateSigningRequest(wire_to_native!(
+                    body.bytes(),
+                    create_certificate_signing_request_proto::Result
+                )),
+            ),
         }
     }
 
@@ -221,6 +801,166 @@ impl Convert for ProtobufConverter {
                 result,
                 psa_verify_hash_proto::Result
             ))),
+            NativeResult::PsaHashCompute(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_compute_proto::Result
+            ))),
+            NativeResult::PsaHashCompare(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_compare_proto::Result
+            ))),
+            NativeResult::PsaMacCompute(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_mac_compute_proto::Result
+            ))),
+            NativeResult::PsaMacVerify(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_mac_verify_proto::Result
+            ))),
+            NativeResult::PsaCipherEncrypt(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_cipher_encrypt_proto::Result),
+            )),
+            NativeResult::PsaCipherDecrypt(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_cipher_decrypt_proto::Result),
+            )),
+            NativeResult::PsaAeadEncrypt(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_encrypt_proto::Result
+            ))),
+            NativeResult::PsaAeadDecrypt(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_decrypt_proto::Result
+            ))),
+            NativeResult::PsaAsymmetricEncrypt(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_asymmetric_encrypt_proto::Result),
+            )),
+            NativeResult::PsaAsymmetricDecrypt(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_asymmetric_decrypt_proto::Result),
+            )),
+            NativeResult::PsaRawKeyAgreement(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_raw_key_agreement_proto::Result),
+            )),
+            NativeResult::PsaGenerateRandom(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_generate_random_proto::Result),
+            )),
+            NativeResult::PsaExportKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_export_key_proto::Result
+            ))),
+            NativeResult::PsaGetKeyAttributes(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_get_key_attributes_proto::Result),
+            )),
+            NativeResult::ListKeys(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                list_keys_proto::Result
+            ))),
+            NativeResult::ListAuthenticators(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, list_authenticators_proto::Result),
+            )),
+            NativeResult::ListClients(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                list_clients_proto::Result
+            ))),
+            NativeResult::DeleteClient(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                delete_client_proto::Result
+            ))),
+            NativeResult::PsaCopyKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_copy_key_proto::Result
+            ))),
+            NativeResult::PsaSignMessage(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_sign_message_proto::Result
+            ))),
+            NativeResult::PsaVerifyMessage(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_verify_message_proto::Result),
+            )),
+            NativeResult::PsaKeyDerivationSetup(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_key_derivation_setup_proto::Result),
+            )),
+            NativeResult::PsaKeyDerivationInputBytes(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_key_derivation_input_bytes_proto::Result),
+            )),
+            NativeResult::PsaKeyDerivationInputKey(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_key_derivation_input_key_proto::Result),
+            )),
+            NativeResult::PsaKeyDerivationOutputBytes(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_key_derivation_output_bytes_proto::Result),
+            )),
+            NativeResult::PsaKeyDerivationOutputKey(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_key_derivation_output_key_proto::Result),
+            )),
+            NativeResult::PsaHashSetup(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_setup_proto::Result
+            ))),
+            NativeResult::PsaHashUpdate(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_update_proto::Result
+            ))),
+            NativeResult::PsaHashFinish(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_finish_proto::Result
+            ))),
+            NativeResult::PsaHashAbort(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_hash_abort_proto::Result
+            ))),
+            NativeResult::PsaCipherEncryptSetup(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_cipher_encrypt_setup_proto::Result),
+            )),
+            NativeResult::PsaCipherDecryptSetup(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_cipher_decrypt_setup_proto::Result),
+            )),
+            NativeResult::PsaCipherUpdate(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_cipher_update_proto::Result
+            ))),
+            NativeResult::PsaCipherFinish(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_cipher_finish_proto::Result
+            ))),
+            NativeResult::PsaCipherAbort(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_cipher_abort_proto::Result
+            ))),
+            NativeResult::PsaAeadEncryptSetup(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_aead_encrypt_setup_proto::Result),
+            )),
+            NativeResult::PsaAeadDecryptSetup(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, psa_aead_decrypt_setup_proto::Result),
+            )),
+            NativeResult::PsaAeadUpdate(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_update_proto::Result
+            ))),
+            NativeResult::PsaAeadFinish(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_finish_proto::Result
+            ))),
+            NativeResult::PsaAeadVerify(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_verify_proto::Result
+            ))),
+            NativeResult::PsaAeadAbort(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                psa_aead_abort_proto::Result
+            ))),
+            NativeResult::CanDoCrypto(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                can_do_crypto_proto::Result
+            ))),
+            NativeResult::PrepareKeyAttestation(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, prepare_key_attestation_proto::Result),
+            )),
+            NativeResult::AttestKey(result) => Ok(ResponseBody::from_bytes(native_to_wire!(
+                result,
+                attest_key_proto::Result
+            ))),
+            NativeResult::CreateCertificateSigningRequest(result) => Ok(ResponseBody::from_bytes(
+                native_to_wire!(result, create_certificate_signing_request_proto::Result),
+            )),
         }
     }
 }
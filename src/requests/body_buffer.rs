@@ -0,0 +1,82 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+//! Storage of request and response bodies.
+#[cfg(feature = "fuzz")]
+use arbitrary::{Arbitrary, Unstructured};
+use bytes::Bytes;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::Deref;
+use zeroize::Zeroize;
+
+/// Bytes of a body, either owned or sharing the memory of the buffer it was parsed from.
+///
+/// Owned bytes are zeroized with the body holding them. Shared bytes are only released: zeroizing
+/// them is left to the owner of the buffer they were parsed from.
+#[derive(Clone)]
+pub(crate) enum BodyBuffer {
+    Owned(Vec<u8>),
+    Shared(Bytes),
+}
+
+impl Deref for BodyBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            BodyBuffer::Owned(bytes) => bytes,
+            BodyBuffer::Shared(bytes) => bytes,
+        }
+    }
+}
+
+impl From<Vec<u8>> for BodyBuffer {
+    fn from(bytes: Vec<u8>) -> Self {
+        BodyBuffer::Owned(bytes)
+    }
+}
+
+impl From<Bytes> for BodyBuffer {
+    fn from(bytes: Bytes) -> Self {
+        BodyBuffer::Shared(bytes)
+    }
+}
+
+impl Zeroize for BodyBuffer {
+    fn zeroize(&mut self) {
+        match self {
+            BodyBuffer::Owned(bytes) => bytes.zeroize(),
+            BodyBuffer::Shared(bytes) => *bytes = Bytes::new(),
+        }
+    }
+}
+
+impl PartialEq for BodyBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl std::fmt::Debug for BodyBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.deref(), f)
+    }
+}
+
+impl Serialize for BodyBuffer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.deref().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BodyBuffer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(BodyBuffer::Owned(Vec::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(feature = "fuzz")]
+impl Arbitrary for BodyBuffer {
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        Ok(BodyBuffer::Owned(Vec::arbitrary(u)?))
+    }
+}
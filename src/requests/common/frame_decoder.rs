@@ -0,0 +1,157 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the incremental decoding of request and response frames from bytes
+//! received in chunks of any size.
+use super::{check_magic_number, header_size, VersionedWireHeader, PREFIX_LEN};
+use crate::requests::{ResponseStatus, Result, WireProtocolVersion};
+use derivative::Derivative;
+use log::error;
+use std::convert::TryFrom;
+use zeroize::Zeroize;
+
+/// Frame fully received, with the raw header and the bytes following it.
+pub(crate) struct Frame {
+    pub(crate) header: VersionedWireHeader,
+    pub(crate) body: Vec<u8>,
+    pub(crate) auth: Vec<u8>,
+}
+
+/// State machine buffering bytes until a whole frame has been received.
+///
+/// Each part of the frame is validated as soon as enough bytes are buffered. If an error is
+/// found, the buffered bytes are discarded.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub(crate) struct FrameDecoder {
+    #[derivative(Debug = "ignore")]
+    buffer: Vec<u8>,
+    // Header of the frame being received, with the length of its body checked against the limit.
+    header: Option<(VersionedWireHeader, usize)>,
+    body_len_limit: usize,
+    with_auth: bool,
+}
+
+impl FrameDecoder {
+    /// Create a decoder for frames with a body smaller than `body_len_limit` bytes. If
+    /// `with_auth` is false, the authentication field is not part of the frames.
+    pub(crate) fn new(body_len_limit: usize, with_auth: bool) -> FrameDecoder {
+        FrameDecoder {
+            buffer: Vec::new(),
+            header: None,
+            body_len_limit,
+            with_auth,
+        }
+    }
+
+    /// Buffer bytes received.
+    pub(crate) fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Check if no bytes of a partial frame are buffered.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.header.is_none() && self.buffer.is_empty()
+    }
+
+    /// Get the next frame fully received, if any.
+    pub(crate) fn decode(&mut self) -> Result<Option<Frame>> {
+        self.decode_frame().inspect_err(|_| self.clear())
+    }
+
+    /// Discard the buffered bytes, wiping the whole capacity of the buffer.
+    fn clear(&mut self) {
+        self.buffer.zeroize();
+        self.header = None;
+    }
+
+    fn decode_frame(&mut self) -> Result<Option<Frame>> {
+        let (header, body_len) = match self.header {
+            Some(header) => header,
+            None => match self.decode_header()? {
+                Some(header) => header,
+                None => return Ok(None),
+            },
+        };
+
+        let auth_len = if self.with_auth {
+            usize::try_from(header.auth_len())?
+        } else {
+            0
+        };
+        let frame_len = body_len + auth_len;
+        if self.buffer.len() < frame_len {
+            return Ok(None);
+        }
+
+        let body = self.buffer[..body_len].to_vec();
+        let auth = self.buffer[body_len..frame_len].to_vec();
+        self.buffer[..frame_len].zeroize();
+        let _ = self.buffer.drain(..frame_len);
+        // Draining moves the bytes of the next frames to the start of the buffer and leaves a
+        // copy of them in its spare capacity.
+        self.buffer.spare_capacity_mut().zeroize();
+        self.header = None;
+
+        Ok(Some(Frame { header, body, auth }))
+    }
+
+    fn decode_header(&mut self) -> Result<Option<(VersionedWireHeader, usize)>> {
+        if self.buffer.len() < 4 {
+            return Ok(None);
+        }
+        check_magic_number(u32::from_le_bytes([
+            self.buffer[0],
+            self.buffer[1],
+            self.buffer[2],
+            self.buffer[3],
+        ]))?;
+
+        if self.buffer.len() < PREFIX_LEN {
+            return Ok(None);
+        }
+        let hdr_size = u16::from_le_bytes([self.buffer[4], self.buffer[5]]);
+        if hdr_size < 2 {
+            error!("Header size {} too small to hold the version", hdr_size);
+            return Err(ResponseStatus::InvalidHeader);
+        }
+
+        if self.buffer.len() < PREFIX_LEN + 2 {
+            return Ok(None);
+        }
+        let (version_maj, version_min) = (self.buffer[PREFIX_LEN], self.buffer[PREFIX_LEN + 1]);
+        let version =
+            WireProtocolVersion::from_version(version_maj, version_min).inspect_err(|_| {
+                error!(
+                    "Wire protocol version {}.{} is not supported",
+                    version_maj, version_min
+                )
+            })?;
+        if hdr_size != header_size(version) {
+            error!(
+                "Expected header size {} for version {}, got {}",
+                header_size(version),
+                version,
+                hdr_size
+            );
+            return Err(ResponseStatus::InvalidHeader);
+        }
+
+        let hdr_end = PREFIX_LEN + usize::from(hdr_size);
+        if self.buffer.len() < hdr_end {
+            return Ok(None);
+        }
+        let header = VersionedWireHeader::from_bytes(hdr_size, &self.buffer[PREFIX_LEN..hdr_end])?;
+        let body_len = header.checked_body_len(self.body_len_limit)?;
+        let _ = self.buffer.drain(..hdr_end);
+        self.header = Some((header, body_len));
+
+        Ok(Some((header, body_len)))
+    }
+}
+
+impl Drop for FrameDecoder {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module defines the flags carried in the header frame.
+use crate::requests::{ResponseStatus, Result};
+#[cfg(feature = "fuzz")]
+use arbitrary::{Arbitrary, Unstructured};
+use log::error;
+use std::ops::BitOr;
+
+/// Set of flags passed in headers as `flags`.
+///
+/// The bits not used by a flag below are reserved and must be zero. Headers with reserved bits
+/// set are rejected, so that flags defined later are not silently ignored by older
+/// implementations.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HeaderFlags(u16);
+
+impl HeaderFlags {
+    /// Set in a request to ask the service to close the session given in the `session` field
+    /// once the request is handled, whatever its outcome. A client can then abort a multi-part
+    /// operation in the same request as its last step.
+    pub const CLOSE_SESSION: HeaderFlags = HeaderFlags(0x0001);
+
+    /// Flags defined by the wire protocol.
+    const DEFINED: [HeaderFlags; 1] = [HeaderFlags::CLOSE_SESSION];
+
+    /// Create an empty set of flags.
+    pub fn empty() -> Self {
+        HeaderFlags(0)
+    }
+
+    /// Create the set of all the flags defined by the wire protocol.
+    pub fn all() -> Self {
+        HeaderFlags::DEFINED
+            .iter()
+            .fold(HeaderFlags::empty(), |all, flag| all | *flag)
+    }
+
+    /// Create a set of flags from the value of the header field.
+    ///
+    /// # Errors
+    /// - if a reserved bit is set, `ResponseStatus::InvalidHeader` is returned.
+    pub fn from_bits(bits: u16) -> Result<Self> {
+        if !HeaderFlags::all().contains(HeaderFlags(bits)) {
+            error!(
+                "Reserved header flags set: {:#06x}",
+                bits & !HeaderFlags::all().bits()
+            );
+            return Err(ResponseStatus::InvalidHeader);
+        }
+
+        Ok(HeaderFlags(bits))
+    }
+
+    /// Value of the flags, to put in the header field.
+    pub fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// Check if no flag is set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Check if all the flags of `other` are set.
+    pub fn contains(self, other: HeaderFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+#[cfg(feature = "fuzz")]
+impl Arbitrary for HeaderFlags {
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        HeaderFlags::from_bits(u16::arbitrary(u)? & HeaderFlags::all().bits())
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl BitOr for HeaderFlags {
+    type Output = HeaderFlags;
+
+    fn bitor(self, other: HeaderFlags) -> HeaderFlags {
+        HeaderFlags(self.0 | other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeaderFlags;
+    use crate::requests::ResponseStatus;
+
+    #[test]
+    fn defined_flags() {
+        let flags = HeaderFlags::from_bits(HeaderFlags::CLOSE_SESSION.bits()).unwrap();
+        assert!(flags.contains(HeaderFlags::CLOSE_SESSION));
+        assert_eq!(HeaderFlags::all(), HeaderFlags::CLOSE_SESSION);
+    }
+
+    #[test]
+    fn reserved_flags() {
+        assert!(HeaderFlags::from_bits(0).unwrap().is_empty());
+        assert_eq!(
+            HeaderFlags::from_bits(!HeaderFlags::all().bits()).unwrap_err(),
+            ResponseStatus::InvalidHeader
+        );
+    }
+}
@@ -3,6 +3,213 @@
 
 //! This module implements the raw wire protocol header frame for requests and responses in
 //! all defined versions of the protocol (currently just 1.0).
+use crate::requests::{ResponseStatus, Result, WireProtocolVersion};
+use log::error;
+use std::convert::TryFrom;
+use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+mod frame_decoder;
+mod header_flags;
 pub mod wire_header_1_0;
 
+pub(crate) use frame_decoder::FrameDecoder;
+pub use header_flags::HeaderFlags;
+
 const MAGIC_NUMBER: u32 = 0x5EC0_A710;
+
+/// Number of bytes of the magic number and header size, which precede the version bytes.
+const PREFIX_LEN: usize = 6;
+
+/// Raw header frame of any of the wire protocol versions supported.
+///
+/// The version of a header read from a stream is found by looking at the version bytes that
+/// follow the magic number and the header size.
+#[derive(Copy, Clone, Debug)]
+pub enum VersionedWireHeader {
+    /// Header frame of version 1.0
+    V1_0(wire_header_1_0::WireHeader),
+}
+
+impl VersionedWireHeader {
+    /// Version of the wire protocol of the header.
+    pub fn version(&self) -> WireProtocolVersion {
+        match self {
+            VersionedWireHeader::V1_0(_) => WireProtocolVersion::V1_0,
+        }
+    }
+
+    /// Number of bytes of content following the header.
+    pub fn body_len(&self) -> u32 {
+        match self {
+            VersionedWireHeader::V1_0(header) => header.body_len,
+        }
+    }
+
+    /// Number of bytes of content following the header, checked against the limit given.
+    ///
+    /// # Errors
+    /// - if the body length is larger than `body_len_limit`, `BodySizeExceedsLimit` is returned.
+    pub(crate) fn checked_body_len(&self, body_len_limit: usize) -> Result<usize> {
+        let body_len = usize::try_from(self.body_len())?;
+        if body_len > body_len_limit {
+            error!(
+                "Body length ({}) bigger than the limit given ({}).",
+                body_len, body_len_limit
+            );
+            return Err(ResponseStatus::BodySizeExceedsLimit);
+        }
+
+        Ok(body_len)
+    }
+
+    /// Number of bytes of authentication following the content.
+    pub fn auth_len(&self) -> u16 {
+        match self {
+            VersionedWireHeader::V1_0(header) => header.auth_len,
+        }
+    }
+
+    /// Serialise the header in the format of its version and write the corresponding bytes to
+    /// the given stream.
+    ///
+    /// # Errors
+    /// - if marshalling the header fails, `ResponseStatus::InvalidEncoding` is returned.
+    /// - if writing the header bytes fails, `ResponseStatus::ConnectionError` is returned.
+    pub fn write_to_stream<W: Write>(&self, stream: &mut W) -> Result<()> {
+        match self {
+            VersionedWireHeader::V1_0(header) => header.write_to_stream(stream),
+        }
+    }
+
+    /// Deserialise a header of any supported version from the given stream.
+    ///
+    /// # Errors
+    /// - if the magic number is invalid, or the header size is invalid for the version used,
+    /// `ResponseStatus::InvalidHeader` is returned.
+    /// - if reading the fields after magic number and header size fails,
+    /// `ResponseStatus::ConnectionError` is returned
+    ///     - the read may fail due to a timeout if not enough bytes are
+    ///     sent across
+    /// - if the parsed bytes cannot be unmarshalled into the contained fields,
+    /// `ResponseStatus::InvalidEncoding` is returned.
+    /// - if the wire protocol version used is not supported,
+    /// `ResponseStatus::WireProtocolVersionNotSupported` is returned.
+    pub fn read_from_stream<R: Read>(mut stream: &mut R) -> Result<VersionedWireHeader> {
+        check_magic_number(get_from_stream!(stream, u32))?;
+
+        let hdr_size = get_from_stream!(stream, u16);
+        let mut bytes = vec![0_u8; usize::try_from(hdr_size)?];
+        stream.read_exact(&mut bytes)?;
+
+        VersionedWireHeader::from_bytes(hdr_size, &bytes)
+    }
+
+    /// Serialise the header in the format of its version and write the corresponding bytes to
+    /// the given asynchronous stream.
+    ///
+    /// # Errors
+    /// Same as `write_to_stream`.
+    #[cfg(feature = "async")]
+    pub async fn write_to_async_stream<W: AsyncWrite + Unpin>(&self, stream: &mut W) -> Result<()> {
+        let mut bytes = Vec::new();
+        self.write_to_stream(&mut bytes)?;
+        stream.write_all(&bytes).await?;
+
+        Ok(())
+    }
+
+    /// Deserialise a header of any supported version from the given asynchronous stream.
+    ///
+    /// # Errors
+    /// Same as `read_from_stream`.
+    #[cfg(feature = "async")]
+    // `read_exact` returns the number of bytes read, which is always the length of the buffer.
+    #[allow(unused_results)]
+    pub async fn read_from_async_stream<R: AsyncRead + Unpin>(
+        stream: &mut R,
+    ) -> Result<VersionedWireHeader> {
+        let mut magic_number = [0_u8; 4];
+        stream.read_exact(&mut magic_number).await?;
+        check_magic_number(u32::from_le_bytes(magic_number))?;
+
+        let mut hdr_size = [0_u8; 2];
+        stream.read_exact(&mut hdr_size).await?;
+        let hdr_size = u16::from_le_bytes(hdr_size);
+        let mut bytes = vec![0_u8; usize::try_from(hdr_size)?];
+        stream.read_exact(&mut bytes).await?;
+
+        VersionedWireHeader::from_bytes(hdr_size, &bytes)
+    }
+
+    /// Deserialise a header of any supported version from the start of a buffer.
+    ///
+    /// The number of bytes taken by the header in the buffer is returned with it. If the buffer
+    /// does not hold the whole header yet, `None` is returned.
+    ///
+    /// # Errors
+    /// Same as `read_from_stream`, except that no `ResponseStatus::ConnectionError` is returned.
+    pub fn parse(bytes: &[u8]) -> Result<Option<(VersionedWireHeader, usize)>> {
+        if bytes.len() < 4 {
+            return Ok(None);
+        }
+        check_magic_number(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))?;
+
+        if bytes.len() < PREFIX_LEN {
+            return Ok(None);
+        }
+        let hdr_size = u16::from_le_bytes([bytes[4], bytes[5]]);
+        let hdr_end = PREFIX_LEN + usize::from(hdr_size);
+        if bytes.len() < hdr_end {
+            return Ok(None);
+        }
+
+        Ok(Some((
+            VersionedWireHeader::from_bytes(hdr_size, &bytes[PREFIX_LEN..hdr_end])?,
+            hdr_end,
+        )))
+    }
+
+    /// Deserialise a header from the bytes following the header size, dispatching on the
+    /// version bytes.
+    fn from_bytes(hdr_size: u16, bytes: &[u8]) -> Result<VersionedWireHeader> {
+        if bytes.len() < 2 {
+            error!("Header size {} too small to hold the version", hdr_size);
+            return Err(ResponseStatus::InvalidHeader);
+        }
+
+        // The two bytes after the header size are the version major and minor.
+        let version = WireProtocolVersion::from_version(bytes[0], bytes[1]).inspect_err(|_| {
+            error!(
+                "Wire protocol version {}.{} is not supported",
+                bytes[0], bytes[1]
+            )
+        })?;
+
+        match version {
+            WireProtocolVersion::V1_0 => Ok(VersionedWireHeader::V1_0(
+                wire_header_1_0::WireHeader::from_bytes(hdr_size, &bytes[2..])?,
+            )),
+        }
+    }
+}
+
+fn check_magic_number(magic_number: u32) -> Result<()> {
+    if magic_number != MAGIC_NUMBER {
+        error!(
+            "Expected magic number {}, got {}",
+            MAGIC_NUMBER, magic_number
+        );
+        return Err(ResponseStatus::InvalidHeader);
+    }
+
+    Ok(())
+}
+
+/// Size of the header frame for the version given, counted from the version bytes.
+fn header_size(version: WireProtocolVersion) -> u16 {
+    match version {
+        WireProtocolVersion::V1_0 => wire_header_1_0::REQUEST_HDR_SIZE,
+    }
+}
@@ -3,19 +3,18 @@
 
 //! This module defines and implements the raw wire protocol header frame for
 //! version 1.0 of the protocol.
-use crate::requests::common::MAGIC_NUMBER;
+use crate::requests::common::{VersionedWireHeader, MAGIC_NUMBER};
 use crate::requests::{ResponseStatus, Result};
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
 use log::error;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
 use std::io::{Read, Write};
 
 const WIRE_PROTOCOL_VERSION_MAJ: u8 = 1;
 const WIRE_PROTOCOL_VERSION_MIN: u8 = 0;
 
-const REQUEST_HDR_SIZE: u16 = 30;
+pub(super) const REQUEST_HDR_SIZE: u16 = 30;
 
 /// Raw representation of a common request/response header, as defined for the wire format.
 ///
@@ -103,20 +102,30 @@ impl WireHeader {
     ///     sent across
     /// - if the parsed bytes cannot be unmarshalled into the contained fields,
     /// `ResponseStatus::InvalidEncoding` is returned.
-    /// - if the wire protocol version used is different than 1.0
-    pub fn read_from_stream<R: Read>(mut stream: &mut R) -> Result<WireHeader> {
-        let magic_number = get_from_stream!(stream, u32);
-        if magic_number != MAGIC_NUMBER {
-            error!(
-                "Expected magic number {}, got {}",
-                MAGIC_NUMBER, magic_number
-            );
-            return Err(ResponseStatus::InvalidHeader);
+    /// - if the wire protocol version used is different than 1.0,
+    /// `ResponseStatus::WireProtocolVersionNotSupported` is returned.
+    pub fn read_from_stream<R: Read>(stream: &mut R) -> Result<WireHeader> {
+        match VersionedWireHeader::read_from_stream(stream)? {
+            VersionedWireHeader::V1_0(header) => Ok(header),
+            // Reachable once other versions are defined.
+            #[allow(unreachable_patterns)]
+            header => {
+                error!(
+                    "Expected wire protocol version 1.0, got {}",
+                    header.version()
+                );
+                Err(ResponseStatus::WireProtocolVersionNotSupported)
+            }
         }
+    }
 
-        let hdr_size = get_from_stream!(stream, u16);
-        let mut bytes = vec![0_u8; usize::try_from(hdr_size)?];
-        stream.read_exact(&mut bytes)?;
+    /// Deserialise a request header from the bytes following the version bytes.
+    ///
+    /// # Errors
+    /// - if the header size is invalid, `ResponseStatus::InvalidHeader` is returned.
+    /// - if the bytes cannot be unmarshalled into the contained fields,
+    /// `ResponseStatus::InvalidEncoding` is returned.
+    pub(super) fn from_bytes(hdr_size: u16, bytes: &[u8]) -> Result<WireHeader> {
         if hdr_size != REQUEST_HDR_SIZE {
             error!(
                 "Expected request header size {}, got {}",
@@ -125,16 +134,6 @@ impl WireHeader {
             return Err(ResponseStatus::InvalidHeader);
         }
 
-        let version_maj = bytes.remove(0); // first byte after hdr length is version maj
-        let version_min = bytes.remove(0); // second byte after hdr length is version min
-        if version_maj != WIRE_PROTOCOL_VERSION_MAJ || version_min != WIRE_PROTOCOL_VERSION_MIN {
-            error!(
-                "Expected wire protocol version {}.{}, got {}.{} instead",
-                WIRE_PROTOCOL_VERSION_MAJ, WIRE_PROTOCOL_VERSION_MIN, version_maj, version_min
-            );
-            return Err(ResponseStatus::WireProtocolVersionNotSupported);
-        }
-
-        Ok(bincode::deserialize(&bytes)?)
+        Ok(bincode::deserialize(bytes)?)
     }
 }
@@ -6,6 +6,7 @@
 //! service returns.
 use num_derive::FromPrimitive;
 
+mod body_buffer;
 mod response_status;
 
 pub mod utils;
@@ -13,7 +14,7 @@ pub mod common;
 pub mod request;
 pub mod response;
 #[cfg(feature = "fuzz")]
-use arbitrary::Arbitrary;
+use arbitrary::{Arbitrary, Unstructured};
 pub use request::Request;
 pub use response::Response;
 pub use response_status::{ResponseStatus, Result};
@@ -91,6 +92,107 @@ pub enum Opcode {
     ListProviders = 8,
     /// ListOpcodes operation
     ListOpcodes = 9,
+    /// PsaAsymmetricEncrypt operation
+    PsaAsymmetricEncrypt = 10,
+    /// PsaAsymmetricDecrypt operation
+    PsaAsymmetricDecrypt = 11,
+    /// PsaExportKey operation
+    PsaExportKey = 12,
+    /// PsaGenerateRandom operation
+    PsaGenerateRandom = 13,
+    /// ListAuthenticators operation
+    ListAuthenticators = 14,
+    /// PsaHashCompute operation
+    PsaHashCompute = 15,
+    /// PsaHashCompare operation
+    PsaHashCompare = 16,
+    /// PsaAeadEncrypt operation
+    PsaAeadEncrypt = 17,
+    /// PsaAeadDecrypt operation
+    PsaAeadDecrypt = 18,
+    /// PsaRawKeyAgreement operation
+    PsaRawKeyAgreement = 19,
+    /// PsaCipherEncrypt operation
+    PsaCipherEncrypt = 20,
+    /// PsaCipherDecrypt operation
+    PsaCipherDecrypt = 21,
+    /// PsaMacCompute operation
+    PsaMacCompute = 22,
+    /// PsaMacVerify operation
+    PsaMacVerify = 23,
+    /// PsaSignMessage operation
+    PsaSignMessage = 24,
+    /// PsaVerifyMessage operation
+    PsaVerifyMessage = 25,
+    /// ListKeys operation
+    ListKeys = 26,
+    /// ListClients operation
+    ListClients = 27,
+    /// DeleteClient operation
+    DeleteClient = 28,
+    /// AttestKey operation
+    AttestKey = 30,
+    /// PrepareKeyAttestation operation
+    PrepareKeyAttestation = 31,
+    /// CanDoCrypto operation
+    CanDoCrypto = 32,
+    /// PsaGetKeyAttributes operation
+    PsaGetKeyAttributes = 33,
+    /// PsaCopyKey operation
+    PsaCopyKey = 34,
+    /// PsaKeyDerivationSetup operation
+    PsaKeyDerivationSetup = 35,
+    /// PsaKeyDerivationInputBytes operation
+    PsaKeyDerivationInputBytes = 36,
+    /// PsaKeyDerivationInputKey operation
+    PsaKeyDerivationInputKey = 37,
+    /// PsaKeyDerivationOutputBytes operation
+    PsaKeyDerivationOutputBytes = 38,
+    /// PsaKeyDerivationOutputKey operation
+    PsaKeyDerivationOutputKey = 39,
+    /// PsaHashSetup operation
+    PsaHashSetup = 40,
+    /// PsaHashUpdate operation
+    PsaHashUpdate = 41,
+    /// PsaHashFinish operation
+    PsaHashFinish = 42,
+    /// PsaHashAbort operation
+    PsaHashAbort = 43,
+    /// PsaCipherEncryptSetup operation
+    PsaCipherEncryptSetup = 44,
+    /// PsaCipherDecryptSetup operation
+    PsaCipherDecryptSetup = 45,
+    /// PsaCipherUpdate operation
+    PsaCipherUpdate = 46,
+    /// PsaCipherFinish operation
+    PsaCipherFinish = 47,
+    /// PsaCipherAbort operation
+    PsaCipherAbort = 48,
+    /// PsaAeadEncryptSetup operation
+    PsaAeadEncryptSetup = 49,
+    /// PsaAeadDecryptSetup operation
+    PsaAeadDecryptSetup = 50,
+    /// PsaAeadUpdate operation
+    PsaAeadUpdate = 51,
+    /// PsaAeadFinish operation
+    PsaAeadFinish = 52,
+    /// PsaAeadVerify operation
+    PsaAeadVerify = 53,
+    /// PsaAeadAbort operation
+    PsaAeadAbort = 54,
+    /// CreateCertificateSigningRequest operation
+    CreateCertificateSigningRequest = 55,
+}
+
+impl Opcode {
+    /// Check if an opcode is one of an admin operation.
+    ///
+    /// Admin operations are sent to the core provider and should only be accepted by the service
+    /// from clients that it considers as administrators. Other clients should get an
+    /// `AdminOperation` response status.
+    pub fn is_admin(self) -> bool {
+        matches!(self, Opcode::ListClients | Opcode::DeleteClient)
+    }
 }
 
 /// Listing of available authentication methods.
@@ -105,3 +207,163 @@ pub enum AuthType {
     /// Direct authentication
     Direct = 1,
 }
+
+/// Versions of the wire protocol understood by this crate.
+///
+/// The version is sent in the `version_maj` and `version_min` bytes of the header frame of every
+/// request and response. The service answers a request with the version it arrived with.
+#[cfg_attr(feature = "fuzz", derive(Arbitrary))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
+pub enum WireProtocolVersion {
+    /// Version 1.0
+    V1_0,
+}
+
+impl WireProtocolVersion {
+    /// Latest version of the wire protocol supported by this crate.
+    pub const LATEST: WireProtocolVersion = WireProtocolVersion::V1_0;
+
+    const SUPPORTED: [WireProtocolVersion; 1] = [WireProtocolVersion::V1_0];
+
+    /// Version major.
+    pub fn major(self) -> u8 {
+        match self {
+            WireProtocolVersion::V1_0 => 1,
+        }
+    }
+
+    /// Version minor.
+    pub fn minor(self) -> u8 {
+        match self {
+            WireProtocolVersion::V1_0 => 0,
+        }
+    }
+
+    /// Get the version matching exactly the version bytes of a header frame.
+    ///
+    /// # Errors
+    /// - if the version is not supported by this crate, `WireProtocolVersionNotSupported` is
+    /// returned.
+    pub fn from_version(version_maj: u8, version_min: u8) -> Result<Self> {
+        WireProtocolVersion::SUPPORTED
+            .iter()
+            .copied()
+            .find(|version| version.major() == version_maj && version.minor() == version_min)
+            .ok_or(ResponseStatus::WireProtocolVersionNotSupported)
+    }
+
+    /// Choose the version to use to talk to a service supporting up to the version given.
+    ///
+    /// Versions with the same major are backward compatible: the highest version supported by
+    /// this crate with the same major and a minor lower or equal to the one given is returned.
+    ///
+    /// # Errors
+    /// - if no such version is supported by this crate, `WireProtocolVersionNotSupported` is
+    /// returned.
+    pub fn negotiate(version_maj: u8, version_min: u8) -> Result<Self> {
+        WireProtocolVersion::SUPPORTED
+            .iter()
+            .copied()
+            .filter(|version| version.major() == version_maj && version.minor() <= version_min)
+            .max()
+            .ok_or(ResponseStatus::WireProtocolVersionNotSupported)
+    }
+}
+
+impl std::fmt::Display for WireProtocolVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major(), self.minor())
+    }
+}
+
+/// Handle of a multi-part operation session.
+///
+/// Returned by the setup operations of multi-part operations, such as `PsaHashSetup`, and passed
+/// in headers as `session` for the following steps. The service copies it in the `session` field
+/// of the response headers of these steps. The value 0 is the default value of the header field
+/// and is never used to identify a session.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct SessionHandle(u64);
+
+impl SessionHandle {
+    /// Create a session handle from its value in the request header.
+    ///
+    /// Returns `None` for the value 0, which means that the request is not part of a session.
+    pub fn new(session: u64) -> Option<Self> {
+        if session == 0 {
+            None
+        } else {
+            Some(SessionHandle(session))
+        }
+    }
+
+    /// Value of the session handle, to put in the `session` field of the request header.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(feature = "fuzz")]
+impl Arbitrary for SessionHandle {
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        SessionHandle::new(u64::arbitrary(u)?).ok_or(arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl std::fmt::Display for SessionHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Opcode, ResponseStatus, SessionHandle, WireProtocolVersion};
+
+    #[test]
+    fn admin_opcodes() {
+        assert!(Opcode::ListClients.is_admin());
+        assert!(Opcode::DeleteClient.is_admin());
+        assert!(!Opcode::ListKeys.is_admin());
+        assert!(!Opcode::Ping.is_admin());
+    }
+
+    #[test]
+    fn session_handle() {
+        assert_eq!(SessionHandle::new(0), None);
+        assert_eq!(SessionHandle::new(0x1234).unwrap().value(), 0x1234);
+    }
+
+    #[test]
+    fn wire_protocol_version() {
+        assert_eq!(
+            WireProtocolVersion::from_version(1, 0).unwrap(),
+            WireProtocolVersion::V1_0
+        );
+        assert_eq!(
+            WireProtocolVersion::from_version(1, 1).unwrap_err(),
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+        assert_eq!(WireProtocolVersion::V1_0.to_string(), "1.0");
+    }
+
+    #[test]
+    fn wire_protocol_version_negotiation() {
+        assert_eq!(
+            WireProtocolVersion::negotiate(1, 0).unwrap(),
+            WireProtocolVersion::V1_0
+        );
+        assert_eq!(
+            WireProtocolVersion::negotiate(1, 7).unwrap(),
+            WireProtocolVersion::V1_0
+        );
+        assert_eq!(
+            WireProtocolVersion::negotiate(2, 0).unwrap_err(),
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+        assert_eq!(
+            WireProtocolVersion::negotiate(0, 9).unwrap_err(),
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+    }
+}
@@ -4,22 +4,27 @@
 //!
 //! A `Request` is to the service to execute one operation.
 use super::common::wire_header_1_0::WireHeader as Raw;
+use super::common::{HeaderFlags, VersionedWireHeader};
 use super::response::ResponseHeader;
-use crate::requests::{ResponseStatus, Result};
+use crate::requests::{ResponseStatus, Result, WireProtocolVersion};
 use crate::secrecy::ExposeSecret;
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
+use bytes::Bytes;
 use derivative::Derivative;
-use log::error;
 use std::convert::{TryFrom, TryInto};
 use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncWrite};
 
 mod request_auth;
 mod request_body;
+mod request_decoder;
 mod request_header;
 
 pub use request_auth::RequestAuth;
 pub use request_body::RequestBody;
+pub use request_decoder::RequestDecoder;
 pub use request_header::RequestHeader;
 
 #[cfg(feature = "testing")]
@@ -56,7 +61,8 @@ impl Request {
 
     /// Serialise request and write it to given stream.
     ///
-    /// Request header is first converted to its raw format before serialization.
+    /// Request header is first converted to the raw format of its wire protocol version before
+    /// serialization.
     ///
     /// # Errors
     /// - if an IO operation fails while writing any of the subfields of the request,
@@ -64,10 +70,7 @@ impl Request {
     /// - if encoding any of the fields in the header fails, `ResponseStatus::InvalidEncoding`
     /// is returned.
     pub fn write_to_stream(self, stream: &mut impl Write) -> Result<()> {
-        let mut raw_header: Raw = self.header.into();
-        raw_header.body_len = u32::try_from(self.body.len())?;
-        raw_header.auth_len = u16::try_from(self.auth.buffer.expose_secret().len())?;
-        raw_header.write_to_stream(stream)?;
+        self.raw_header()?.write_to_stream(stream)?;
 
         self.body.write_to_stream(stream)?;
         self.auth.write_to_stream(stream)?;
@@ -77,7 +80,8 @@ impl Request {
 
     /// Deserialise request from given stream.
     ///
-    /// Request header is parsed from its raw form, ensuring that all fields are valid.
+    /// Request header is parsed from its raw form, ensuring that all fields are valid. The
+    /// version of the wire protocol used by the request is kept in the header.
     /// The `body_len_limit` parameter allows the interface client to reject requests that are
     /// longer than a predefined limit. The length limit is in bytes.
     ///
@@ -87,17 +91,79 @@ impl Request {
     /// - if the request body size specified in the header is larger than the limit passed as
     /// a parameter, `BodySizeExceedsLimit` will be returned.
     pub fn read_from_stream(stream: &mut impl Read, body_len_limit: usize) -> Result<Request> {
-        let raw_header = Raw::read_from_stream(stream)?;
-        let body_len = usize::try_from(raw_header.body_len)?;
-        if body_len > body_len_limit {
-            error!(
-                "Request body length ({}) bigger than the limit given ({}).",
-                body_len, body_len_limit
-            );
-            return Err(ResponseStatus::BodySizeExceedsLimit);
-        }
+        let raw_header = VersionedWireHeader::read_from_stream(stream)?;
+        let body_len = raw_header.checked_body_len(body_len_limit)?;
         let body = RequestBody::read_from_stream(stream, body_len)?;
-        let auth = RequestAuth::read_from_stream(stream, usize::try_from(raw_header.auth_len)?)?;
+        let auth = RequestAuth::read_from_stream(stream, usize::try_from(raw_header.auth_len())?)?;
+
+        Ok(Request {
+            header: raw_header.try_into()?,
+            body,
+            auth,
+        })
+    }
+
+    /// Parse a request from the start of a buffer, without copying its body.
+    ///
+    /// The body of the request shares the memory of `buffer`. The authentication field is copied
+    /// so that it is zeroized when the request is dropped. The number of bytes taken by the
+    /// request in the buffer is returned with it, so that the requests following it can be parsed.
+    /// If the buffer does not hold the whole request yet, `None` is returned.
+    ///
+    /// # Errors
+    /// Same as `read_from_stream`, except that no `ResponseStatus::ConnectionError` is returned.
+    pub fn parse(buffer: &Bytes, body_len_limit: usize) -> Result<Option<(Request, usize)>> {
+        let (raw_header, hdr_len) = match VersionedWireHeader::parse(buffer)? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+        let body_end = hdr_len + raw_header.checked_body_len(body_len_limit)?;
+        let auth_end = body_end + usize::try_from(raw_header.auth_len())?;
+        if buffer.len() < auth_end {
+            return Ok(None);
+        }
+
+        Ok(Some((
+            Request {
+                header: raw_header.try_into()?,
+                body: RequestBody::from_shared_bytes(buffer.slice(hdr_len..body_end)),
+                auth: RequestAuth::new(buffer[body_end..auth_end].to_vec()),
+            },
+            auth_end,
+        )))
+    }
+
+    /// Serialise request and write it to given asynchronous stream.
+    ///
+    /// # Errors
+    /// Same as `write_to_stream`.
+    #[cfg(feature = "async")]
+    pub async fn write_to_async_stream(self, stream: &mut (impl AsyncWrite + Unpin)) -> Result<()> {
+        self.raw_header()?.write_to_async_stream(stream).await?;
+
+        self.body.write_to_async_stream(stream).await?;
+        self.auth.write_to_async_stream(stream).await?;
+
+        Ok(())
+    }
+
+    /// Deserialise request from given asynchronous stream.
+    ///
+    /// The `body_len_limit` parameter is used as in `read_from_stream`.
+    ///
+    /// # Errors
+    /// Same as `read_from_stream`.
+    #[cfg(feature = "async")]
+    pub async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        body_len_limit: usize,
+    ) -> Result<Request> {
+        let raw_header = VersionedWireHeader::read_from_async_stream(stream).await?;
+        let body_len = raw_header.checked_body_len(body_len_limit)?;
+        let body = RequestBody::read_from_async_stream(stream, body_len).await?;
+        let auth =
+            RequestAuth::read_from_async_stream(stream, usize::try_from(raw_header.auth_len())?)
+                .await?;
 
         Ok(Request {
             header: raw_header.try_into()?,
@@ -105,6 +171,21 @@ impl Request {
             auth,
         })
     }
+
+    /// Convert the header to the raw format of its wire protocol version, filling in the length
+    /// of the body and of the authentication field.
+    fn raw_header(&self) -> Result<VersionedWireHeader> {
+        let body_len = u32::try_from(self.body.len())?;
+        let auth_len = u16::try_from(self.auth.buffer.expose_secret().len())?;
+        match self.header.version {
+            WireProtocolVersion::V1_0 => {
+                let mut raw_header: Raw = self.header.into();
+                raw_header.body_len = body_len;
+                raw_header.auth_len = auth_len;
+                Ok(VersionedWireHeader::V1_0(raw_header))
+            }
+        }
+    }
 }
 
 #[cfg(feature = "testing")]
@@ -115,10 +196,13 @@ impl Default for Request {
 }
 
 /// Conversion from `RequestHeader` to `ResponseHeader` is useful for
-/// when reversing data flow, from handling a request to handling a response.
+/// when reversing data flow, from handling a request to handling a response. The response uses
+/// the same wire protocol version as the request.
 impl From<RequestHeader> for ResponseHeader {
     fn from(req_hdr: RequestHeader) -> ResponseHeader {
         ResponseHeader {
+            version: req_hdr.version,
+            flags: HeaderFlags::empty(),
             provider: req_hdr.provider,
             session: req_hdr.session,
             content_type: req_hdr.accept_type,
@@ -131,7 +215,7 @@ impl From<RequestHeader> for ResponseHeader {
 #[cfg(test)]
 mod tests {
     use super::super::utils::tests as test_utils;
-    use super::super::{AuthType, BodyType, Opcode, ProviderID, ResponseStatus};
+    use super::super::{AuthType, BodyType, Opcode, ProviderID, Response, ResponseStatus};
     use super::*;
 
     #[test]
@@ -198,6 +282,7 @@ mod tests {
         let resp_hdr: ResponseHeader = req_hdr.into();
 
         let mut resp_hdr_exp = ResponseHeader::new();
+        resp_hdr_exp.version = WireProtocolVersion::V1_0;
         resp_hdr_exp.provider = ProviderID::Core;
         resp_hdr_exp.session = 0x11_22_33_44_55_66_77_88;
         resp_hdr_exp.content_type = BodyType::Protobuf;
@@ -226,10 +311,220 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrong_header_size() {
+        let mut mock = test_utils::MockReadWrite {
+            buffer: get_request_bytes(),
+        };
+        // Announce a header one byte shorter than the one of version 1.0.
+        mock.buffer[4] = 0x1d;
+
+        let response_status =
+            Request::read_from_stream(&mut mock, 1000).expect_err("Should have failed.");
+
+        assert_eq!(response_status, ResponseStatus::InvalidHeader);
+    }
+
+    #[test]
+    fn response_uses_request_version() {
+        let mut mock = test_utils::MockReadWrite {
+            buffer: get_request_bytes(),
+        };
+        let request = Request::read_from_stream(&mut mock, 1000).expect("Failed to read request");
+        assert_eq!(request.header.version, WireProtocolVersion::V1_0);
+
+        let response = Response::from_request_header(request.header, ResponseStatus::Success);
+        assert_eq!(response.header.version, WireProtocolVersion::V1_0);
+
+        let mut mock = test_utils::MockReadWrite { buffer: Vec::new() };
+        response
+            .write_to_stream(&mut mock)
+            .expect("Failed to write response");
+        // Version major and minor follow the magic number and the header size.
+        assert_eq!(mock.buffer[6..8], [0x01, 0x00]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_request_to_stream() {
+        let mut buffer = Vec::new();
+        get_request()
+            .write_to_async_stream(&mut buffer)
+            .await
+            .expect("Failed to write request");
+
+        assert_eq!(buffer, get_request_bytes());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_stream_to_request() {
+        let bytes = get_request_bytes();
+        let request = Request::read_from_async_stream(&mut bytes.as_slice(), 1000)
+            .await
+            .expect("Failed to read request");
+        let exp_req = get_request();
+
+        assert_eq!(request.header, exp_req.header);
+        assert_eq!(request.body, exp_req.body);
+        assert_eq!(
+            request.auth.buffer.expose_secret(),
+            exp_req.auth.buffer.expose_secret()
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_body_too_large() {
+        let bytes = get_request_bytes();
+        let response_status = Request::read_from_async_stream(&mut bytes.as_slice(), 0)
+            .await
+            .expect_err("Should have failed.");
+
+        assert_eq!(response_status, ResponseStatus::BodySizeExceedsLimit);
+    }
+
+    #[test]
+    fn decoder_byte_by_byte() {
+        let mut decoder = RequestDecoder::new(1000);
+        let bytes = get_request_bytes();
+        let (last, bytes) = bytes.split_last().unwrap();
+        for byte in bytes {
+            decoder.push(&[*byte]);
+            assert!(decoder
+                .decode()
+                .expect("Failed to decode request")
+                .is_none());
+        }
+        assert!(!decoder.is_empty());
+
+        decoder.push(&[*last]);
+        let request = decoder
+            .decode()
+            .expect("Failed to decode request")
+            .expect("Request should be complete");
+        let exp_req = get_request();
+
+        assert_eq!(request.header, exp_req.header);
+        assert_eq!(request.body, exp_req.body);
+        assert_eq!(
+            request.auth.buffer.expose_secret(),
+            exp_req.auth.buffer.expose_secret()
+        );
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn decoder_several_requests() {
+        let mut decoder = RequestDecoder::new(1000);
+        let mut bytes = get_request_bytes();
+        bytes.extend(get_request_bytes());
+        decoder.push(&bytes);
+
+        assert!(decoder.decode().unwrap().is_some());
+        assert!(decoder.decode().unwrap().is_some());
+        assert!(decoder.decode().unwrap().is_none());
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn decoder_wrong_magic_number() {
+        let mut decoder = RequestDecoder::new(1000);
+        // The magic number is checked before the rest of the header is received.
+        decoder.push(&[0xFF, 0xFF, 0xFF, 0xFF]);
+
+        assert_eq!(
+            decoder.decode().expect_err("Should have failed."),
+            ResponseStatus::InvalidHeader
+        );
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn decoder_body_too_large() {
+        let mut decoder = RequestDecoder::new(0);
+        // The body length is checked before the body is received.
+        decoder.push(&get_request_bytes()[..36]);
+
+        assert_eq!(
+            decoder.decode().expect_err("Should have failed."),
+            ResponseStatus::BodySizeExceedsLimit
+        );
+    }
+
+    #[test]
+    fn parse_request() {
+        let mut bytes = get_request_bytes();
+        bytes.extend(get_request_bytes());
+        let buffer = Bytes::from(bytes);
+
+        let (request, len) = Request::parse(&buffer, 1000)
+            .expect("Failed to parse request")
+            .expect("Request is incomplete");
+        let exp_req = get_request();
+        assert_eq!(len, get_request_bytes().len());
+        assert_eq!(request.header, exp_req.header);
+        assert_eq!(request.body, exp_req.body);
+        assert_eq!(
+            request.auth.buffer.expose_secret(),
+            exp_req.auth.buffer.expose_secret()
+        );
+        // The body points into the buffer.
+        assert_eq!(request.body.bytes().as_ptr(), buffer[36..].as_ptr());
+
+        let (request, _) = Request::parse(&buffer.slice(len..), 1000)
+            .expect("Failed to parse")
+            .expect("Request is incomplete");
+        assert_eq!(request.header, exp_req.header);
+    }
+
+    #[test]
+    fn parse_truncated_request() {
+        let buffer = Bytes::from(get_request_bytes());
+
+        // Truncated in the header, and after it.
+        assert!(Request::parse(&buffer.slice(..10), 1000)
+            .expect("Failed to parse")
+            .is_none());
+        assert!(Request::parse(&buffer.slice(..40), 1000)
+            .expect("Failed to parse")
+            .is_none());
+        let response_status = Request::parse(&buffer, 0).expect_err("Should have failed.");
+        assert_eq!(response_status, ResponseStatus::BodySizeExceedsLimit);
+    }
+
+    #[test]
+    fn reserved_flags() {
+        let mut mock = test_utils::MockReadWrite {
+            buffer: get_request_bytes(),
+        };
+        // Set a reserved bit of the flags field.
+        mock.buffer[9] = 0x80;
+
+        let response_status =
+            Request::read_from_stream(&mut mock, 1000).expect_err("Should have failed.");
+
+        assert_eq!(response_status, ResponseStatus::InvalidHeader);
+    }
+
+    #[test]
+    fn flags_round_trip() {
+        let mut header = get_request().header;
+        header.flags = HeaderFlags::CLOSE_SESSION;
+
+        let raw = Raw::from(header);
+        assert_eq!(raw.flags, HeaderFlags::CLOSE_SESSION.bits());
+
+        let header = RequestHeader::try_from(raw).expect("Failed to convert header");
+        assert_eq!(header.flags, HeaderFlags::CLOSE_SESSION);
+    }
+
     fn get_request() -> Request {
         let body = RequestBody::from_bytes(vec![0x70, 0x80, 0x90]);
         let auth = RequestAuth::new(vec![0xa0, 0xb0, 0xc0]);
         let header = RequestHeader {
+            version: WireProtocolVersion::V1_0,
+            flags: HeaderFlags::empty(),
             provider: ProviderID::Core,
             session: 0x11_22_33_44_55_66_77_88,
             content_type: BodyType::Protobuf,
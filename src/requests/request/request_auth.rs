@@ -5,6 +5,8 @@ use crate::secrecy::{ExposeSecret, Secret};
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
 use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 
 /// Wrapper around the authentication value of a request.
 ///
@@ -38,4 +40,27 @@ impl RequestAuth {
         stream.write_all(&self.buffer.expose_secret())?;
         Ok(())
     }
+
+    /// Read an authentication field from an asynchronous stream, given its length in bytes.
+    #[cfg(feature = "async")]
+    pub(super) async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        len: usize,
+    ) -> Result<RequestAuth> {
+        let mut buffer = vec![0; len];
+        let _ = stream.read_exact(&mut buffer).await?;
+        Ok(RequestAuth {
+            buffer: Secret::new(buffer),
+        })
+    }
+
+    /// Write an authentication field to an asynchronous stream.
+    #[cfg(feature = "async")]
+    pub(super) async fn write_to_async_stream(
+        &self,
+        stream: &mut (impl AsyncWrite + Unpin),
+    ) -> Result<()> {
+        stream.write_all(self.buffer.expose_secret()).await?;
+        Ok(())
+    }
 }
@@ -1,9 +1,13 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
+use crate::requests::body_buffer::BodyBuffer;
 use crate::requests::Result;
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
+use bytes::Bytes;
 use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use zeroize::Zeroize;
 
 /// Wrapper around the body of a request.
@@ -13,7 +17,7 @@ use zeroize::Zeroize;
 #[derive(Debug, PartialEq, Zeroize)]
 #[zeroize(drop)]
 pub struct RequestBody {
-    buffer: Vec<u8>,
+    buffer: BodyBuffer,
 }
 
 impl RequestBody {
@@ -21,13 +25,17 @@ impl RequestBody {
     /// Available for testing only.
     #[cfg(feature = "testing")]
     pub(super) fn new() -> RequestBody {
-        RequestBody { bytes: Vec::new() }
+        RequestBody {
+            buffer: Vec::new().into(),
+        }
     }
 
     /// Read the request body from a stream, given the length of the content.
     pub(super) fn read_from_stream(mut stream: &mut impl Read, len: usize) -> Result<RequestBody> {
         let buffer = get_from_stream!(stream; len);
-        Ok(RequestBody { buffer })
+        Ok(RequestBody {
+            buffer: buffer.into(),
+        })
     }
 
     /// Write the request body to a stream.
@@ -36,9 +44,41 @@ impl RequestBody {
         Ok(())
     }
 
+    /// Read a request body from an asynchronous stream, given the number of bytes it contains.
+    #[cfg(feature = "async")]
+    pub(super) async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        len: usize,
+    ) -> Result<RequestBody> {
+        let mut buffer = vec![0; len];
+        let _ = stream.read_exact(&mut buffer).await?;
+        Ok(RequestBody {
+            buffer: buffer.into(),
+        })
+    }
+
+    /// Write a request body to an asynchronous stream.
+    #[cfg(feature = "async")]
+    pub(super) async fn write_to_async_stream(
+        &self,
+        stream: &mut (impl AsyncWrite + Unpin),
+    ) -> Result<()> {
+        stream.write_all(&self.buffer).await?;
+        Ok(())
+    }
+
     /// Create a `RequestBody` from a vector of bytes.
     pub(crate) fn from_bytes(buffer: Vec<u8>) -> RequestBody {
-        RequestBody { buffer }
+        RequestBody {
+            buffer: buffer.into(),
+        }
+    }
+
+    /// Create a `RequestBody` sharing the memory of a buffer, without copying it.
+    pub(crate) fn from_shared_bytes(buffer: Bytes) -> RequestBody {
+        RequestBody {
+            buffer: buffer.into(),
+        }
     }
 
     /// Get the body as a slice of bytes.
@@ -61,6 +101,8 @@ impl RequestBody {
     /// Must only be used for testing purposes.
     #[cfg(feature = "testing")]
     pub fn _from_bytes(bytes: Vec<u8>) -> RequestBody {
-        RequestBody { bytes }
+        RequestBody {
+            buffer: bytes.into(),
+        }
     }
 }
@@ -0,0 +1,60 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::{Request, RequestAuth, RequestBody};
+use crate::requests::common::FrameDecoder;
+use crate::requests::Result;
+use std::convert::TryInto;
+
+/// Incremental decoder of requests.
+///
+/// Meant for event loops in which the bytes of requests are received in chunks of any size. The
+/// bytes received are given to `push`, after which `decode` is called until it returns
+/// `Ok(None)`, each call returning the next request fully received.
+///
+/// The magic number, header size and wire protocol version are checked as soon as enough bytes
+/// have been received, and the body length as soon as the whole header has been. If the frame is
+/// invalid, the buffered bytes are discarded: as the start of the next request cannot be found,
+/// the connection should then be closed.
+#[derive(Debug)]
+pub struct RequestDecoder {
+    frame_decoder: FrameDecoder,
+}
+
+impl RequestDecoder {
+    /// Create a decoder rejecting requests with a body longer than `body_len_limit` bytes.
+    pub fn new(body_len_limit: usize) -> RequestDecoder {
+        RequestDecoder {
+            frame_decoder: FrameDecoder::new(body_len_limit, true),
+        }
+    }
+
+    /// Buffer bytes received from the stream.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.frame_decoder.push(bytes);
+    }
+
+    /// Decode the next request fully received, if any.
+    ///
+    /// # Errors
+    /// - if the frame is invalid, the same `ResponseStatus` as `Request::read_from_stream` is
+    /// returned and the buffered bytes are discarded.
+    /// - if a field of the header has an invalid value, the corresponding `ResponseStatus` is
+    /// returned. The request is skipped and the decoder can be used for the next ones.
+    pub fn decode(&mut self) -> Result<Option<Request>> {
+        match self.frame_decoder.decode()? {
+            Some(frame) => Ok(Some(Request {
+                header: frame.header.try_into()?,
+                body: RequestBody::from_bytes(frame.body),
+                auth: RequestAuth::new(frame.auth),
+            })),
+            None => Ok(None),
+        }
+    }
+
+    /// Check if no bytes of a partial request are buffered.
+    ///
+    /// When the stream is closed, this tells if the last request was truncated.
+    pub fn is_empty(&self) -> bool {
+        self.frame_decoder.is_empty()
+    }
+}
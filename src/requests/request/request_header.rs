@@ -1,8 +1,9 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::requests::common::wire_header_1_0::WireHeader as Raw;
+use crate::requests::common::{HeaderFlags, VersionedWireHeader};
 use crate::requests::ResponseStatus;
-use crate::requests::{AuthType, BodyType, Opcode, ProviderID};
+use crate::requests::{AuthType, BodyType, Opcode, ProviderID, WireProtocolVersion};
 #[cfg(feature = "fuzz")]
 use arbitrary::Arbitrary;
 use num::FromPrimitive;
@@ -15,6 +16,10 @@ use std::convert::TryFrom;
 #[cfg_attr(feature = "fuzz", derive(Arbitrary))]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct RequestHeader {
+    /// Version of the wire protocol used to send the header.
+    pub version: WireProtocolVersion,
+    /// Flags of the header.
+    pub flags: HeaderFlags,
     /// Provider ID value
     pub provider: ProviderID,
     /// Session handle
@@ -35,6 +40,8 @@ impl RequestHeader {
     #[cfg(feature = "testing")]
     pub(crate) fn new() -> RequestHeader {
         RequestHeader {
+            version: WireProtocolVersion::LATEST,
+            flags: HeaderFlags::empty(),
             provider: ProviderID::Core,
             session: 0,
             content_type: BodyType::Protobuf,
@@ -73,6 +80,8 @@ impl TryFrom<Raw> for RequestHeader {
         };
 
         Ok(RequestHeader {
+            version: WireProtocolVersion::V1_0,
+            flags: HeaderFlags::from_bits(header.flags)?,
             provider: ProviderID::try_from(header.provider)?,
             session: header.session,
             content_type,
@@ -83,6 +92,17 @@ impl TryFrom<Raw> for RequestHeader {
     }
 }
 
+/// Conversion from the raw header of any wire protocol version to native request header.
+impl TryFrom<VersionedWireHeader> for RequestHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: VersionedWireHeader) -> ::std::result::Result<Self, Self::Error> {
+        match header {
+            VersionedWireHeader::V1_0(header) => RequestHeader::try_from(header),
+        }
+    }
+}
+
 /// Conversion from native to raw request header.
 ///
 /// This is required in order to bring the contents of the header in a state
@@ -90,7 +110,7 @@ impl TryFrom<Raw> for RequestHeader {
 impl From<RequestHeader> for Raw {
     fn from(header: RequestHeader) -> Self {
         Raw {
-            flags: 0,
+            flags: header.flags.bits(),
             provider: header.provider as u8,
             session: header.session,
             content_type: header.content_type as u8,
@@ -3,17 +3,23 @@
 //! Response definition
 
 use super::common::wire_header_1_0::WireHeader as Raw;
+use super::common::VersionedWireHeader;
 use super::request::RequestHeader;
 use super::ResponseStatus;
 use super::Result;
-use log::error;
+use super::WireProtocolVersion;
+use bytes::Bytes;
 use std::convert::{TryFrom, TryInto};
 use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncWrite};
 
 mod response_body;
+mod response_decoder;
 mod response_header;
 
 pub use response_body::ResponseBody;
+pub use response_decoder::ResponseDecoder;
 pub use response_header::ResponseHeader;
 
 #[cfg(feature = "testing")]
@@ -41,8 +47,8 @@ impl Response {
 
     /// Convert request into an error response with a given `ResponseStatus`.
     ///
-    /// The relevant fields in the header are preserved and an empty body is provided
-    /// by default.
+    /// The relevant fields in the header, including the wire protocol version, are preserved and
+    /// an empty body is provided by default.
     pub fn from_request_header(header: RequestHeader, status: ResponseStatus) -> Response {
         let mut response = Response::new();
         response.header = header.into();
@@ -61,7 +67,7 @@ impl Response {
 
     /// Serialise response and write it to given stream.
     ///
-    /// Header is converted to a raw format before serializing.
+    /// Header is converted to the raw format of its wire protocol version before serializing.
     ///
     /// # Errors
     /// - if writing any of the subfields (header or body) fails, then
@@ -69,10 +75,7 @@ impl Response {
     /// - if encoding any of the fields in the header fails, then
     /// `ResponseStatus::InvalidEncoding` is returned.
     pub fn write_to_stream(self, stream: &mut impl Write) -> Result<()> {
-        let mut raw_header: Raw = self.header.into();
-        raw_header.body_len = u32::try_from(self.body.len())?;
-
-        raw_header.write_to_stream(stream)?;
+        self.raw_header()?.write_to_stream(stream)?;
         self.body.write_to_stream(stream)?;
 
         Ok(())
@@ -89,15 +92,8 @@ impl Response {
     /// - if the request body size specified in the header is larger than the limit passed as
     /// a parameter, `BodySizeExceedsLimit` will be returned.
     pub fn read_from_stream(stream: &mut impl Read, body_len_limit: usize) -> Result<Response> {
-        let raw_header = Raw::read_from_stream(stream)?;
-        let body_len = usize::try_from(raw_header.body_len)?;
-        if body_len > body_len_limit {
-            error!(
-                "Request body length ({}) bigger than the limit given ({}).",
-                body_len, body_len_limit
-            );
-            return Err(ResponseStatus::BodySizeExceedsLimit);
-        }
+        let raw_header = VersionedWireHeader::read_from_stream(stream)?;
+        let body_len = raw_header.checked_body_len(body_len_limit)?;
         let body = ResponseBody::read_from_stream(stream, body_len)?;
 
         Ok(Response {
@@ -105,10 +101,84 @@ impl Response {
             body,
         })
     }
+
+    /// Parse a response from the start of a buffer, without copying its body.
+    ///
+    /// The body of the response shares the memory of `buffer`. The number of bytes taken by the
+    /// response in the buffer is returned with it. If the buffer does not hold the whole response
+    /// yet, `None` is returned.
+    ///
+    /// # Errors
+    /// Same as `read_from_stream`, except that no `ResponseStatus::ConnectionError` is returned.
+    pub fn parse(buffer: &Bytes, body_len_limit: usize) -> Result<Option<(Response, usize)>> {
+        let (raw_header, hdr_len) = match VersionedWireHeader::parse(buffer)? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+        let body_end = hdr_len + raw_header.checked_body_len(body_len_limit)?;
+        if buffer.len() < body_end {
+            return Ok(None);
+        }
+
+        Ok(Some((
+            Response {
+                header: raw_header.try_into()?,
+                body: ResponseBody::from_shared_bytes(buffer.slice(hdr_len..body_end)),
+            },
+            body_end,
+        )))
+    }
+
+    /// Serialise response and write it to given asynchronous stream.
+    ///
+    /// # Errors
+    /// Same as `write_to_stream`.
+    #[cfg(feature = "async")]
+    pub async fn write_to_async_stream(self, stream: &mut (impl AsyncWrite + Unpin)) -> Result<()> {
+        self.raw_header()?.write_to_async_stream(stream).await?;
+        self.body.write_to_async_stream(stream).await?;
+
+        Ok(())
+    }
+
+    /// Deserialise response from given asynchronous stream.
+    ///
+    /// The `body_len_limit` parameter is used as in `read_from_stream`.
+    ///
+    /// # Errors
+    /// Same as `read_from_stream`.
+    #[cfg(feature = "async")]
+    pub async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        body_len_limit: usize,
+    ) -> Result<Response> {
+        let raw_header = VersionedWireHeader::read_from_async_stream(stream).await?;
+        let body_len = raw_header.checked_body_len(body_len_limit)?;
+        let body = ResponseBody::read_from_async_stream(stream, body_len).await?;
+
+        Ok(Response {
+            header: raw_header.try_into()?,
+            body,
+        })
+    }
+
+    /// Convert the header to the raw format of its wire protocol version, filling in the length
+    /// of the body.
+    fn raw_header(&self) -> Result<VersionedWireHeader> {
+        let body_len = u32::try_from(self.body.len())?;
+        match self.header.version {
+            WireProtocolVersion::V1_0 => {
+                let mut raw_header: Raw = self.header.into();
+                raw_header.body_len = body_len;
+                Ok(VersionedWireHeader::V1_0(raw_header))
+            }
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::super::common::HeaderFlags;
     use super::super::utils::tests as test_utils;
     use super::super::{BodyType, Opcode, ProviderID, ResponseStatus};
     use super::*;
@@ -185,9 +255,97 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_response_to_stream() {
+        let mut buffer = Vec::new();
+        get_response()
+            .write_to_async_stream(&mut buffer)
+            .await
+            .expect("Failed to write response");
+
+        assert_eq!(buffer, get_response_bytes());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_stream_to_response() {
+        let bytes = get_response_bytes();
+        let response = Response::read_from_async_stream(&mut bytes.as_slice(), 1000)
+            .await
+            .expect("Failed to read response");
+
+        assert_eq!(response, get_response());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_wrong_version() {
+        let mut bytes = get_response_bytes();
+        // Put an invalid version major field.
+        bytes[6] = 0xFF;
+
+        let response_status = Response::read_from_async_stream(&mut bytes.as_slice(), 1000)
+            .await
+            .expect_err("Should have failed.");
+
+        assert_eq!(
+            response_status,
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+    }
+
+    #[test]
+    fn decoder_in_chunks() {
+        let mut decoder = ResponseDecoder::new(1000);
+        let bytes = get_response_bytes();
+
+        decoder.push(&bytes[..10]);
+        assert!(decoder.decode().unwrap().is_none());
+        decoder.push(&bytes[10..37]);
+        assert!(decoder.decode().unwrap().is_none());
+        decoder.push(&bytes[37..]);
+        let response = decoder
+            .decode()
+            .expect("Failed to decode response")
+            .expect("Response should be complete");
+
+        assert_eq!(response, get_response());
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn decoder_wrong_version() {
+        let mut decoder = ResponseDecoder::new(1000);
+        // The version is checked before the rest of the header is received.
+        decoder.push(&[0x10, 0xA7, 0xC0, 0x5E, 0x1e, 0x00, 0xFF, 0xFF]);
+
+        assert_eq!(
+            decoder.decode().expect_err("Should have failed."),
+            ResponseStatus::WireProtocolVersionNotSupported
+        );
+    }
+
+    #[test]
+    fn parse_response() {
+        let buffer = Bytes::from(get_response_bytes());
+
+        let (response, len) = Response::parse(&buffer, 1000)
+            .expect("Failed to parse response")
+            .expect("Response is incomplete");
+        assert_eq!(response, get_response());
+        assert_eq!(len, buffer.len());
+
+        assert!(Response::parse(&buffer.slice(..37), 1000)
+            .expect("Failed to parse")
+            .is_none());
+    }
+
     fn get_response() -> Response {
         let body = ResponseBody::from_bytes(vec![0x70, 0x80, 0x90]);
         let header = ResponseHeader {
+            version: WireProtocolVersion::V1_0,
+            flags: HeaderFlags::empty(),
             provider: ProviderID::Core,
             session: 0x11_22_33_44_55_66_77_88,
             content_type: BodyType::Protobuf,
@@ -1,8 +1,12 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use super::Result;
+use crate::requests::body_buffer::BodyBuffer;
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use std::io::{Read, Write};
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use zeroize::Zeroize;
 
 /// Wrapper around the body of a response.
@@ -11,19 +15,23 @@ use zeroize::Zeroize;
 #[derive(Debug, Serialize, Deserialize, PartialEq, Zeroize)]
 #[zeroize(drop)]
 pub struct ResponseBody {
-    buffer: Vec<u8>,
+    buffer: BodyBuffer,
 }
 
 impl ResponseBody {
     /// Create a new empty response body.
     pub(crate) fn new() -> ResponseBody {
-        ResponseBody { buffer: Vec::new() }
+        ResponseBody {
+            buffer: Vec::new().into(),
+        }
     }
 
     /// Read a response body from a stream, given the number of bytes it contains.
     pub(super) fn read_from_stream(mut stream: &mut impl Read, len: usize) -> Result<ResponseBody> {
         let buffer = get_from_stream!(stream; len);
-        Ok(ResponseBody { buffer })
+        Ok(ResponseBody {
+            buffer: buffer.into(),
+        })
     }
 
     /// Write a response body to a stream.
@@ -32,9 +40,41 @@ impl ResponseBody {
         Ok(())
     }
 
+    /// Read a response body from an asynchronous stream, given the number of bytes it contains.
+    #[cfg(feature = "async")]
+    pub(super) async fn read_from_async_stream(
+        stream: &mut (impl AsyncRead + Unpin),
+        len: usize,
+    ) -> Result<ResponseBody> {
+        let mut buffer = vec![0; len];
+        let _ = stream.read_exact(&mut buffer).await?;
+        Ok(ResponseBody {
+            buffer: buffer.into(),
+        })
+    }
+
+    /// Write a response body to an asynchronous stream.
+    #[cfg(feature = "async")]
+    pub(super) async fn write_to_async_stream(
+        &self,
+        stream: &mut (impl AsyncWrite + Unpin),
+    ) -> Result<()> {
+        stream.write_all(&self.buffer).await?;
+        Ok(())
+    }
+
     /// Create a `ResponseBody` from a vector of bytes.
     pub(crate) fn from_bytes(buffer: Vec<u8>) -> ResponseBody {
-        ResponseBody { buffer }
+        ResponseBody {
+            buffer: buffer.into(),
+        }
+    }
+
+    /// Create a `ResponseBody` sharing the memory of a buffer, without copying it.
+    pub(crate) fn from_shared_bytes(buffer: Bytes) -> ResponseBody {
+        ResponseBody {
+            buffer: buffer.into(),
+        }
     }
 
     /// Get the body as a slice of bytes.
@@ -0,0 +1,59 @@
+// Copyright 2020 Contributors to the Parsec project.
+// SPDX-License-Identifier: Apache-2.0
+use super::{Response, ResponseBody};
+use crate::requests::common::FrameDecoder;
+use crate::requests::Result;
+use std::convert::TryInto;
+
+/// Incremental decoder of responses.
+///
+/// Meant for event loops in which the bytes of responses are received in chunks of any size. The
+/// bytes received are given to `push`, after which `decode` is called until it returns
+/// `Ok(None)`, each call returning the next response fully received.
+///
+/// The magic number, header size and wire protocol version are checked as soon as enough bytes
+/// have been received, and the body length as soon as the whole header has been. If the frame is
+/// invalid, the buffered bytes are discarded: as the start of the next response cannot be found,
+/// the connection should then be closed.
+#[derive(Debug)]
+pub struct ResponseDecoder {
+    frame_decoder: FrameDecoder,
+}
+
+impl ResponseDecoder {
+    /// Create a decoder rejecting responses with a body longer than `body_len_limit` bytes.
+    pub fn new(body_len_limit: usize) -> ResponseDecoder {
+        ResponseDecoder {
+            frame_decoder: FrameDecoder::new(body_len_limit, false),
+        }
+    }
+
+    /// Buffer bytes received from the stream.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.frame_decoder.push(bytes);
+    }
+
+    /// Decode the next response fully received, if any.
+    ///
+    /// # Errors
+    /// - if the frame is invalid, the same `ResponseStatus` as `Response::read_from_stream` is
+    /// returned and the buffered bytes are discarded.
+    /// - if a field of the header has an invalid value, the corresponding `ResponseStatus` is
+    /// returned. The response is skipped and the decoder can be used for the next ones.
+    pub fn decode(&mut self) -> Result<Option<Response>> {
+        match self.frame_decoder.decode()? {
+            Some(frame) => Ok(Some(Response {
+                header: frame.header.try_into()?,
+                body: ResponseBody::from_bytes(frame.body),
+            })),
+            None => Ok(None),
+        }
+    }
+
+    /// Check if no bytes of a partial response are buffered.
+    ///
+    /// When the stream is closed, this tells if the last response was truncated.
+    pub fn is_empty(&self) -> bool {
+        self.frame_decoder.is_empty()
+    }
+}
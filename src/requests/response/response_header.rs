@@ -1,7 +1,8 @@
 // Copyright 2019 Contributors to the Parsec project.
 // SPDX-License-Identifier: Apache-2.0
 use crate::requests::common::wire_header_1_0::WireHeader as Raw;
-use crate::requests::{BodyType, Opcode, ProviderID, ResponseStatus, Result};
+use crate::requests::common::{HeaderFlags, VersionedWireHeader};
+use crate::requests::{BodyType, Opcode, ProviderID, ResponseStatus, Result, WireProtocolVersion};
 use num::FromPrimitive;
 use std::convert::TryFrom;
 
@@ -11,6 +12,10 @@ use std::convert::TryFrom;
 /// not copied across from the raw header.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct ResponseHeader {
+    /// Version of the wire protocol used to send the header.
+    pub version: WireProtocolVersion,
+    /// Flags of the header.
+    pub flags: HeaderFlags,
     /// Provider ID value
     pub provider: ProviderID,
     /// Session handle
@@ -27,6 +32,8 @@ impl ResponseHeader {
     /// Create a new response header with default field values.
     pub(crate) fn new() -> ResponseHeader {
         ResponseHeader {
+            version: WireProtocolVersion::LATEST,
+            flags: HeaderFlags::empty(),
             provider: ProviderID::Core,
             session: 0,
             content_type: BodyType::Protobuf,
@@ -64,6 +71,8 @@ impl TryFrom<Raw> for ResponseHeader {
         };
 
         Ok(ResponseHeader {
+            version: WireProtocolVersion::V1_0,
+            flags: HeaderFlags::from_bits(header.flags)?,
             provider,
             session: header.session,
             content_type,
@@ -73,6 +82,17 @@ impl TryFrom<Raw> for ResponseHeader {
     }
 }
 
+/// Conversion from the raw header of any wire protocol version to native response header.
+impl TryFrom<VersionedWireHeader> for ResponseHeader {
+    type Error = ResponseStatus;
+
+    fn try_from(header: VersionedWireHeader) -> Result<Self> {
+        match header {
+            VersionedWireHeader::V1_0(header) => ResponseHeader::try_from(header),
+        }
+    }
+}
+
 /// Conversion from native to raw response header.
 ///
 /// This is required in order to bring the contents of the header in a state
@@ -80,7 +100,7 @@ impl TryFrom<Raw> for ResponseHeader {
 impl From<ResponseHeader> for Raw {
     fn from(header: ResponseHeader) -> Self {
         Raw {
-            flags: 0,
+            flags: header.flags.bits(),
             provider: header.provider as u8,
             session: header.session,
             content_type: header.content_type as u8,
@@ -34,5 +34,6 @@ fi
 # Unit tests and doc tests #
 ############################
 RUST_BACKTRACE=1 cargo test
+RUST_BACKTRACE=1 cargo test --features async
 
 cargo clean